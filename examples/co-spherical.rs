@@ -61,7 +61,7 @@ fn main() {
 			println!("Sample with accuracy: 1{epsilon:+.1e}");
 			ball
 		})
-		.min()
+		.min_by(Ball::cmp_radius)
 		.unwrap();
 	println!();
 	let epsilon = ball.radius_squared / radius_squared - 1.0;
@@ -78,7 +78,7 @@ fn main() {
 			println!("Sample with accuracy: 1{epsilon:+.1e}");
 			ball
 		})
-		.min()
+		.min_by(Ball::cmp_radius)
 		.unwrap();
 	println!();
 	let epsilon = ball.radius_squared / radius_squared - 1.0;
@@ -95,7 +95,7 @@ fn main() {
 			println!("Sample with accuracy: 1{epsilon:+.1e}");
 			ball
 		})
-		.min()
+		.min_by(Ball::cmp_radius)
 		.unwrap();
 	println!();
 	let epsilon = ball.radius_squared / radius_squared - 1.0;
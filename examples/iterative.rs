@@ -0,0 +1,56 @@
+use miniball::{
+	nalgebra::{Point3, Vector3},
+	{Ball, Enclosing},
+};
+use std::{
+	collections::VecDeque,
+	time::{Duration, Instant},
+};
+
+type T = f64;
+
+fn main() {
+	let m = 1_000_000;
+	let s = 8;
+	let points = (0..m)
+		.map(|_| Point3::from(Vector3::<T>::new_random()))
+		.collect::<VecDeque<_>>();
+
+	println!("Compares the recursive with the iterative version of Welzl's algorithm.");
+	println!("Takes minimum duration of {s} samples each starting with the same order of points.");
+	println!("Run in release mode to compare meaningful durations.");
+	println!();
+	println!("n = 3, m = {m}, random");
+	println!();
+	let recursive = (0..s)
+		.map(|_| {
+			let mut points = points.clone();
+			let time = Instant::now();
+			let ball = Ball::enclosing_points(&mut points);
+			let time = time.elapsed();
+			println!("Recursive sample: {time:?} (radius {:.6})", ball.radius());
+			time
+		})
+		.min()
+		.unwrap_or(Duration::ZERO);
+	println!();
+	let iterative = (0..s)
+		.map(|_| {
+			let mut points = points.clone();
+			let mut stack = VecDeque::with_capacity(m);
+			let time = Instant::now();
+			let ball = Ball::enclosing_points_iterative(&mut points, &mut stack);
+			let time = time.elapsed();
+			println!("Iterative sample: {time:?} (radius {:.6})", ball.radius());
+			time
+		})
+		.min()
+		.unwrap_or(Duration::ZERO);
+	println!();
+	println!("Recursive result: {recursive:?}");
+	println!("Iterative result: {iterative:?}");
+	println!(
+		"Iterative/recursive: {:.2}",
+		iterative.as_secs_f64() / recursive.as_secs_f64()
+	);
+}
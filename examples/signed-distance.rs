@@ -0,0 +1,58 @@
+use miniball::{
+	nalgebra::{Point3, Vector3},
+	Ball, BallWithRadius,
+};
+use std::time::{Duration, Instant};
+
+type T = f64;
+
+fn main() {
+	let m = 1_000_000;
+	let s = 8;
+	let ball = Ball::new(Point3::from(Vector3::<T>::new_random()), 0.5);
+	let cached = BallWithRadius::from(ball);
+	let points = (0..m)
+		.map(|_| Point3::from(Vector3::<T>::new_random()))
+		.collect::<Vec<_>>();
+
+	println!("Compares signed distances of a ball with and without caching its radius.");
+	println!("Takes minimum duration of {s} samples each iterating the same points.");
+	println!("Run in release mode to compare meaningful durations.");
+	println!();
+	println!("n = 3, m = {m}, random");
+	println!();
+	let uncached = (0..s)
+		.map(|_| {
+			let time = Instant::now();
+			let sum = points
+				.iter()
+				.map(|point| ball.signed_distance(point))
+				.sum::<T>();
+			let time = time.elapsed();
+			println!("Uncached sample: {time:?} (sum {sum:.6})");
+			time
+		})
+		.min()
+		.unwrap_or(Duration::ZERO);
+	println!();
+	let cached = (0..s)
+		.map(|_| {
+			let time = Instant::now();
+			let sum = points
+				.iter()
+				.map(|point| cached.signed_distance(point))
+				.sum::<T>();
+			let time = time.elapsed();
+			println!("Cached sample: {time:?} (sum {sum:.6})");
+			time
+		})
+		.min()
+		.unwrap_or(Duration::ZERO);
+	println!();
+	println!("Uncached result: {uncached:?}");
+	println!("Cached result: {cached:?}");
+	println!(
+		"Cached/uncached: {:.2}",
+		cached.as_secs_f64() / uncached.as_secs_f64()
+	);
+}
@@ -4,14 +4,47 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::Enclosing;
+use super::enclosing::enclosing_points_by;
+use super::{deque, Deque, Ellipsoid, Enclosing, Intersection, Location, WithBoundsError};
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
 use core::cmp::Ordering;
+use nalgebra::try_convert;
 use nalgebra::{
-	base::allocator::Allocator, DefaultAllocator, DimName, OMatrix, OPoint, OVector, RealField,
+	base::allocator::Allocator, AbstractRotation, Const, DefaultAllocator, DimDiff, DimMin,
+	DimName, DimNameAdd, DimNameSum, DimSub, Isometry, OMatrix, OPoint, OVector, Point2, Point3,
+	RealField, Rotation, SMatrix, SVector, U1, U2, U3, U4,
 };
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(all(feature = "rayon", feature = "rand"))]
+use rand::{seq::SliceRandom, thread_rng};
+#[cfg(all(feature = "rayon", feature = "rand"))]
+use rayon::iter::IntoParallelIterator;
+#[cfg(feature = "rayon")]
+use rayon::iter::ParallelIterator;
+#[cfg(feature = "robust")]
+use robust::{orient2d, orient3d, Coord, Coord3D};
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+#[cfg(feature = "std")]
+use stacker::maybe_grow;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
 
 /// Ball over real field `T` of dimension `D` with center and radius squared.
+///
+/// With the `serde` feature, deserialization fails if the radius squared is negative or not
+/// finite unless it is the negative one of [`Ball::empty()`].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "T: Serialize, OPoint<T, D>: Serialize",
+		deserialize = "T: Deserialize<'de>, OPoint<T, D>: Deserialize<'de>"
+	))
+)]
 pub struct Ball<T: RealField, D: DimName>
 where
 	DefaultAllocator: Allocator<T, D>,
@@ -19,77 +52,1664 @@ where
 	/// Ball's center.
 	pub center: OPoint<T, D>,
 	/// Ball's radius squared.
+	#[cfg_attr(
+		feature = "serde",
+		serde(deserialize_with = "deserialize_radius_squared")
+	)]
 	pub radius_squared: T,
 }
 
-impl<T: RealField + Copy, D: DimName> Copy for Ball<T, D>
-where
-	OPoint<T, D>: Copy,
-	DefaultAllocator: Allocator<T, D>,
-{
+/// Ball of dimension 2, i.e., a disk.
+pub type Ball2<T> = Ball<T, U2>;
+/// Ball of dimension 3.
+pub type Ball3<T> = Ball<T, U3>;
+/// Ball of dimension 4.
+pub type Ball4<T> = Ball<T, U4>;
+
+/// Deserializes radius squared and fails if it is negative or not finite unless it is the one of
+/// [`Ball::empty()`].
+#[cfg(feature = "serde")]
+fn deserialize_radius_squared<'de, T: RealField + Deserialize<'de>, E: Deserializer<'de>>(
+	deserializer: E,
+) -> Result<T, E::Error> {
+	let radius_squared = T::deserialize(deserializer)?;
+	if radius_squared >= T::zero() && radius_squared.is_finite() || radius_squared == -T::one() {
+		Ok(radius_squared)
+	} else {
+		Err(E::Error::custom("negative or infinite radius squared"))
+	}
 }
 
-impl<T: RealField, D: DimName> PartialEq for Ball<T, D>
+impl<T: RealField, D: DimName> Ball<T, D>
 where
 	DefaultAllocator: Allocator<T, D>,
 {
-	fn eq(&self, other: &Self) -> bool {
+	/// Ball with `center` and `radius`.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `radius` is negative or not finite.
+	#[must_use]
+	#[inline]
+	pub fn new(center: OPoint<T, D>, radius: T) -> Self {
+		debug_assert!(
+			radius >= T::zero() && radius.is_finite(),
+			"negative or infinite radius"
+		);
+		Self {
+			center,
+			radius_squared: radius.clone() * radius,
+		}
+	}
+	/// Ball with `center` and `radius_squared`.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `radius_squared` is negative or not finite.
+	#[must_use]
+	#[inline]
+	pub fn from_radius_squared(center: OPoint<T, D>, radius_squared: T) -> Self {
+		debug_assert!(
+			radius_squared >= T::zero() && radius_squared.is_finite(),
+			"negative or infinite radius squared"
+		);
+		Self {
+			center,
+			radius_squared,
+		}
+	}
+	/// Ball with `a` and `b` as antipodal points.
+	///
+	/// The center is the midpoint and the radius is half the distance of `a` and `b`. This is the
+	/// minimum ball enclosing both points.
+	#[must_use]
+	pub fn from_diameter(a: &OPoint<T, D>, b: &OPoint<T, D>) -> Self {
+		let two = T::one() + T::one();
+		let diameter = b - a;
+		Self {
+			radius_squared: diameter.norm_squared() / (two.clone() * two.clone()),
+			center: a + diameter / two,
+		}
+	}
+	/// Empty ball centered at the origin with negative radius squared, see [`Self::is_empty()`].
+	///
+	/// This is the identity for building up a ball incrementally, e.g., by folding points via
+	/// [`Self::expand_to_contain()`]:
+	///
+	///   * [`Enclosing::contains()`] is `false` for every point.
+	///   * [`Self::expand_to_contain()`] adopts the point as center with zero radius.
+	///   * [`Self::merged()`] returns the other ball, or an empty ball if both are empty.
+	///   * [`Self::intersects()`] is `false` and [`Self::intersection_kind()`] is
+	///     [`Intersection::Disjoint`] for every other ball, including empty ones.
+	///   * [`Self::contains_ball()`] is `true` for an empty other ball, otherwise `false`.
+	///   * Deserialization with the `serde` feature accepts its radius squared of minus one.
+	///
+	/// Other methods are not aware of empty balls, e.g., [`Self::radius()`] is not a number.
+	#[must_use]
+	#[inline]
+	pub fn empty() -> Self {
+		Self {
+			center: OPoint::origin(),
+			radius_squared: -T::one(),
+		}
+	}
+	/// Ball of sphere `a * x.norm_squared() + b.dot(x) + c = 0`, see [`Self::general_form()`].
+	///
+	/// The coefficients are normalized by `a`, so the center is `-b / (2 * a)` and the radius
+	/// squared is `center.norm_squared() - c / a`.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `a` is zero or if the resulting radius squared is negative or
+	/// not finite, i.e., if the equation does not describe a real sphere.
+	#[must_use]
+	pub fn from_general_form(a: T, b: &OVector<T, D>, c: T) -> Self {
+		debug_assert!(a != T::zero(), "zero quadratic coefficient");
+		let two = T::one() + T::one();
+		let center = OPoint::from(b / (-two * a.clone()));
+		let radius_squared = center.coords.norm_squared() - c / a;
+		Self::from_radius_squared(center, radius_squared)
+	}
+	/// Minimum ball enclosing `points`.
+	///
+	/// Collects `points` into an internal deque and invokes [`Enclosing::enclosing_points()`].
+	/// Use the latter with your own deque to reuse its permutation of the move-to-front heuristic
+	/// across invocations.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn from_points(points: impl IntoIterator<Item = OPoint<T, D>>) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		Self::enclosing_points(&mut points.into_iter().collect::<VecDeque<_>>())
+	}
+	/// Returns circumscribed ball with all `bounds` on surface or why it does not exist.
+	///
+	/// Same as [`Enclosing::with_bounds()`] but distinguishes the reasons of failure, e.g., to drop
+	/// a redundant bound and retry.
+	///
+	/// # Errors
+	///
+	/// Returns [`WithBoundsError::EmptyBounds`] if `bounds` is empty,
+	/// [`WithBoundsError::TooManyBounds`] if there are more than `D + 1` bounds,
+	/// [`WithBoundsError::AffinelyDependent`] if the bounds are affinely dependent, and
+	/// [`WithBoundsError::NonFinite`] if the center or the radius are not finite, e.g., due to
+	/// infinite or NaN bounds.
+	pub fn with_bounds_result(bounds: &[OPoint<T, D>]) -> Result<Self, WithBoundsError>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let ball = Self::with_bounds_coefficients(bounds).map(|(ball, _coefficients)| ball);
+		#[cfg(feature = "robust")]
+		if matches!(ball, Err(WithBoundsError::AffinelyDependent))
+			&& matches!((D::USIZE, bounds.len()), (2, 3) | (3, 4))
+		{
+			return Self::with_robust_bounds(bounds);
+		}
+		ball
+	}
+	/// Returns circumscribed ball with all `bounds` on surface and barycentric weights of center.
+	///
+	/// The center is the affine combination `Σ weights[i] * bounds[i]` with weights summing to one.
+	/// Weights of missing bounds, i.e., beyond the `bounds.len()` of fewer than `D + 1` bounds,
+	/// are zero. All weights are non-negative if and only if the center lies within the simplex
+	/// spanned by the bounds. Returns `None` in the same cases as [`Enclosing::with_bounds()`] but
+	/// never uses the exact orientation predicates of the `robust` feature.
+	#[must_use]
+	#[allow(clippy::type_complexity)]
+	pub fn with_bounds_barycentric(
+		bounds: &[OPoint<T, D>],
+	) -> Option<(Self, OVector<T, DimNameSum<D, U1>>)>
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<T, DimNameSum<D, U1>>,
+	{
+		let (ball, coefficients) = Self::with_bounds_coefficients(bounds).ok()?;
+		let length = bounds.len() - 1;
+		let mut weights = OVector::<T, DimNameSum<D, U1>>::zeros();
+		weights[0] = T::one();
+		for bound in 0..length {
+			weights[0] -= coefficients[bound].clone();
+			weights[bound + 1] = coefficients[bound].clone();
+		}
+		Some((ball, weights))
+	}
+	/// Returns circumscribed ball and coefficients of its center relative to the first bound.
+	///
+	/// The center is `bounds[0] + Σ coefficients[i] * (bounds[i + 1] - bounds[0])`.
+	fn with_bounds_coefficients(
+		bounds: &[OPoint<T, D>],
+	) -> Result<(Self, OVector<T, D>), WithBoundsError>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let length = bounds
+			.len()
+			.checked_sub(1)
+			.ok_or(WithBoundsError::EmptyBounds)?;
+		if length > D::USIZE {
+			return Err(WithBoundsError::TooManyBounds);
+		}
+		let points = OMatrix::<T, D, D>::from_fn(|row, column| {
+			if column < length {
+				bounds[column + 1].coords[row].clone() - bounds[0].coords[row].clone()
+			} else {
+				T::zero()
+			}
+		});
+		let points = points.view((0, 0), (D::USIZE, length));
+		let matrix = OMatrix::<T, D, D>::from_fn(|row, column| {
+			if row < length && column < length {
+				points.column(row).dot(&points.column(column)) * (T::one() + T::one())
+			} else {
+				T::zero()
+			}
+		});
+		let matrix = matrix.view((0, 0), (length, length));
+		let vector = OVector::<T, D>::from_fn(|row, _column| {
+			if row < length {
+				points.column(row).norm_squared()
+			} else {
+				T::zero()
+			}
+		});
+		let vector = vector.view((0, 0), (length, 1));
+		let matrix = matrix
+			.try_inverse()
+			.ok_or(WithBoundsError::AffinelyDependent)?;
+		let vector = matrix * vector;
+		let mut center = OVector::<T, D>::zeros();
+		let mut coefficients = OVector::<T, D>::zeros();
+		for point in 0..length {
+			center += points.column(point) * vector[point].clone();
+			coefficients[point] = vector[point].clone();
+		}
+		let radius_squared = center.norm_squared();
+		let center = &bounds[0] + &center;
+		if !radius_squared.is_finite() || !center.iter().all(T::is_finite) {
+			return Err(WithBoundsError::NonFinite);
+		}
+		let ball = Self {
+			center,
+			radius_squared,
+		};
+		Ok((ball, coefficients))
+	}
+	/// Ball's radius.
+	#[must_use]
+	#[inline]
+	pub fn radius(&self) -> T {
+		self.radius_squared.clone().sqrt()
+	}
+	/// Whether ball is empty, i.e., its radius squared is negative, see [`Self::empty()`].
+	#[must_use]
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.radius_squared < T::zero()
+	}
+	/// Compares ball with `other` ball by radius.
+	///
+	/// Unlike [`PartialEq`] comparing centers as well, this ignores the centers, e.g., for finding
+	/// the smallest ball via [`Iterator::min_by()`].
+	///
+	/// # Panics
+	///
+	/// Panics if the radius of either ball is not a number.
+	#[must_use]
+	pub fn cmp_radius(&self, other: &Self) -> Ordering {
+		self.radius_squared
+			.partial_cmp(&other.radius_squared)
+			.expect("infinite ball")
+	}
+	/// Whether ball is degenerate, i.e., of zero radius.
+	///
+	/// This is the case for the minimum ball enclosing identical points. See
+	/// [`Self::is_degenerate_with_tolerance()`] for near-degenerate balls.
+	#[must_use]
+	#[inline]
+	pub fn is_degenerate(&self) -> bool {
+		self.radius_squared.is_zero()
+	}
+	/// Whether ball is degenerate within absolute `epsilon`, i.e., its radius is at most `epsilon`.
+	#[must_use]
+	#[inline]
+	pub fn is_degenerate_with_tolerance(&self, epsilon: T) -> bool {
+		self.radius_squared <= epsilon.clone() * epsilon
+	}
+	/// Whether ball approximately equals `other` ball within absolute `epsilon`.
+	///
+	/// Tests whether both the distance of the centers and the difference of the radii are at most
+	/// `epsilon`. Unlike [`PartialEq`], this tolerates floating-point inaccuracies.
+	#[must_use]
+	pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+		let epsilon_squared = epsilon.clone() * epsilon;
+		let radius_difference = other.radius() - self.radius();
+		(&other.center - &self.center).norm_squared() <= epsilon_squared
+			&& radius_difference.clone() * radius_difference <= epsilon_squared
+	}
+	/// Ball's volume, i.e., the `D`-dimensional Lebesgue measure of its interior.
+	///
+	/// Computes `V = π^(D/2) / Γ(D/2 + 1) * r^D` by the recurrence `Vₙ = 2π/n * Vₙ₋₂` of the unit
+	/// ball starting at `V₀ = 1` and `V₁ = 2`.
+	///
+	/// # Panics
+	///
+	/// Panics if dimension `D` is not representable in `T`.
+	#[must_use]
+	pub fn volume(&self) -> T {
+		let half = T::from_usize(D::USIZE).unwrap() / (T::one() + T::one());
+		Self::unit_volume() * self.radius_squared.clone().powf(half)
+	}
+	/// Ball's surface area, i.e., the `(D - 1)`-dimensional measure of its surface.
+	///
+	/// Computes `S = D * V / r` as the derivative of [`Self::volume()`] with respect to the radius.
+	///
+	/// # Panics
+	///
+	/// Panics if dimension `D` is not representable in `T`.
+	#[must_use]
+	pub fn surface_area(&self) -> T {
+		let dimension = T::from_usize(D::USIZE).unwrap();
+		let half = (dimension.clone() - T::one()) / (T::one() + T::one());
+		dimension * Self::unit_volume() * self.radius_squared.clone().powf(half)
+	}
+	/// Volume of unit ball.
+	fn unit_volume() -> T {
+		let mut volume = if D::USIZE % 2 == 0 {
+			T::one()
+		} else {
+			T::one() + T::one()
+		};
+		for dimension in (D::USIZE % 2 + 2..=D::USIZE).step_by(2) {
+			volume *= T::two_pi() / T::from_usize(dimension).unwrap();
+		}
+		volume
+	}
+	/// Gradient of radius of ball circumscribed by `bounds` with respect to coordinates of
+	/// `bounds[support]`.
+	///
+	/// Moving a bound moves the center as well. The gradient is the outward unit normal at the
+	/// bound scaled by its barycentric weight of the center, see
+	/// [`Self::with_bounds_barycentric()`]. For two antipodal bounds, each weight is one half. The
+	/// gradient is zero for a single bound as the radius stays zero. Returns `None` in the same
+	/// cases as [`Enclosing::with_bounds()`] but never uses the exact orientation predicates of the
+	/// `robust` feature.
+	///
+	/// # Panics
+	///
+	/// Panics if `support` is out of bounds.
+	#[must_use]
+	pub fn radius_gradient_wrt_support(
+		bounds: &[OPoint<T, D>],
+		support: usize,
+	) -> Option<OVector<T, D>>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let (ball, coefficients) = Self::with_bounds_coefficients(bounds).ok()?;
+		let weight = if support == 0 {
+			(0..bounds.len() - 1).fold(T::one(), |weight, bound| {
+				weight - coefficients[bound].clone()
+			})
+		} else {
+			coefficients[support - 1].clone()
+		};
+		let radius = ball.radius();
+		if radius.is_zero() {
+			return Some(OVector::<T, D>::zeros());
+		}
+		Some((&bounds[support] - ball.center) * (weight / radius))
+	}
+	/// Returns outward unit normal and orthonormal basis at `surface_point` of tangent frame.
+	///
+	/// The last column of the basis is the normal and the other columns span the tangent space.
+	/// The basis is the Householder reflection mapping the last axis to the normal with its last
+	/// column negated if required. Only the direction of `surface_point` from the center is
+	/// considered. If it is within a relative epsilon of the radius to the center, the direction
+	/// is undefined and the normal along the first axis is returned instead like in
+	/// [`Self::project_to_surface()`].
+	#[must_use]
+	pub fn tangent_frame(&self, surface_point: &OPoint<T, D>) -> (OVector<T, D>, OMatrix<T, D, D>)
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let min_norm = self.radius() * T::default_epsilon();
+		let normal = (surface_point - &self.center)
+			.try_normalize(min_norm)
+			.unwrap_or_else(|| {
+				let mut normal = OVector::zeros();
+				normal[0] = T::one();
+				normal
+			});
+		let last = D::USIZE - 1;
+		// Reflect the last axis onto normal or its opposite, whichever avoids cancellation.
+		let sign = if normal[last] > T::zero() {
+			T::one()
+		} else {
+			-T::one()
+		};
+		let mut vector = normal.clone();
+		vector[last] += sign;
+		let scale = (T::one() + T::one()) / vector.norm_squared();
+		let mut basis = OMatrix::<T, D, D>::from_fn(|row, column| {
+			let identity = if row == column { T::one() } else { T::zero() };
+			identity - vector[row].clone() * vector[column].clone() * scale.clone()
+		});
+		basis.column_mut(last).copy_from(&normal);
+		(normal, basis)
+	}
+	/// Returns uniform scale and translation mapping ball to the unit ball at the origin.
+	///
+	/// The transform maps `point` to `point * scale + translation` with a scale of `1 / radius`
+	/// and a translation of `-center / radius`, see [`Self::to_unit()`] and its inverse
+	/// [`Self::from_unit()`]. The scale is infinite for a ball of zero radius.
+	#[must_use]
+	pub fn to_unit_transform(&self) -> (T, OVector<T, D>) {
+		let scale = T::one() / self.radius_squared.clone().sqrt();
+		let translation = -&self.center.coords * scale.clone();
+		(scale, translation)
+	}
+	/// Maps `point` from the frame of the ball to the frame of the unit ball at the origin.
+	///
+	/// See [`Self::to_unit_transform()`].
+	#[must_use]
+	pub fn to_unit(&self, point: &OPoint<T, D>) -> OPoint<T, D> {
+		(point - &self.center)
+			.unscale(self.radius_squared.clone().sqrt())
+			.into()
+	}
+	/// Maps `point` from the frame of the unit ball at the origin to the frame of the ball.
+	///
+	/// Inverse of [`Self::to_unit()`].
+	#[must_use]
+	pub fn from_unit(&self, point: &OPoint<T, D>) -> OPoint<T, D> {
+		&self.center + point.coords.scale(self.radius_squared.clone().sqrt())
+	}
+	/// Whether ball contains `point` with relative `epsilon` of radius squared.
+	///
+	/// Tests `radius_squared >= (point - center).norm_squared() * (1 - epsilon)`, i.e., whether
+	/// [`Self::locate_with_tolerance()`] is not [`Location::Outside`]. [`Enclosing::contains()`]
+	/// uses an `epsilon` of `T::default_epsilon().sqrt()`. A degenerate ball of zero radius
+	/// contains its center only.
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is infinite.
+	#[must_use]
+	pub fn contains_with_tolerance(&self, point: &OPoint<T, D>, epsilon: T) -> bool {
+		self.locate_with_tolerance(point, epsilon) != Location::Outside
+	}
+	/// Locates `point` strictly inside, on the surface, or outside of ball.
+	///
+	/// Same as [`Self::locate_with_tolerance()`] with the `epsilon` of [`Enclosing::contains()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is infinite.
+	#[must_use]
+	pub fn locate(&self, point: &OPoint<T, D>) -> Location {
+		self.locate_with_tolerance(point, T::default_epsilon().sqrt())
+	}
+	/// Whether ball contains `point` or `None` if `point` is infinite or NaN.
+	///
+	/// Same as [`Enclosing::contains()`] but does not panic, e.g., to filter out invalid points.
+	#[must_use]
+	pub fn try_contains(&self, point: &OPoint<T, D>) -> Option<bool> {
+		let norm_squared = (point - &self.center).norm_squared();
+		norm_squared.is_finite().then(|| self.contains(point))
+	}
+	/// Locates `point` strictly inside, on the surface, or outside of ball with relative `epsilon`.
+	///
+	/// Compares the signed difference `radius_squared - norm_squared` of the squared distance
+	/// `norm_squared` of `point` to the center against `±epsilon * norm_squared`. As the tolerance
+	/// is relative to the distance of `point`, it does not enlarge a degenerate ball of zero
+	/// radius, see [`Self::is_degenerate()`]. Its center is on its surface and any other point is
+	/// outside for an `epsilon` less than one.
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is infinite.
+	#[must_use]
+	pub fn locate_with_tolerance(&self, point: &OPoint<T, D>, epsilon: T) -> Location {
+		let norm_squared = (point - &self.center).norm_squared();
+		assert!(norm_squared.is_finite(), "infinite point");
+		let difference = self.radius_squared.clone() - norm_squared.clone();
+		let tolerance = norm_squared * epsilon;
+		if difference < -tolerance.clone() {
+			Location::Outside
+		} else if difference > tolerance {
+			Location::Inside
+		} else {
+			Location::Surface
+		}
+	}
+	/// Whether spherical shell between inner radius and ball's radius contains `point`.
+	///
+	/// Tests `inner_radius_squared <= (point - center).norm_squared() <= radius_squared` with the
+	/// same relative tolerance as [`Enclosing::contains()`] on both surfaces.
+	#[must_use]
+	pub fn shell_contains(&self, inner_radius_squared: T, point: &OPoint<T, D>) -> bool {
+		debug_assert!(
+			inner_radius_squared <= self.radius_squared,
+			"inner radius exceeds radius"
+		);
+		let norm_squared = (point - &self.center).norm_squared();
+		self.contains(point)
+			&& norm_squared >= inner_radius_squared * (T::one() - T::default_epsilon().sqrt())
+	}
+	/// Returns weighted fraction of points contained by ball.
+	///
+	/// Divides the sum of weights of contained points by the total weight of `weighted_points`.
+	///
+	/// # Panics
+	///
+	/// Panics if the total weight is not positive.
+	#[must_use]
+	pub fn weighted_coverage<'a>(
+		&self,
+		weighted_points: impl IntoIterator<Item = (&'a OPoint<T, D>, T)>,
+	) -> T {
+		let (contained, total) = weighted_points.into_iter().fold(
+			(T::zero(), T::zero()),
+			|(contained, total), (point, weight)| {
+				if self.contains(point) {
+					(contained + weight.clone(), total + weight)
+				} else {
+					(contained, total + weight)
+				}
+			},
+		);
+		assert!(total > T::zero(), "non-positive total weight");
+		contained / total
+	}
+	/// Whether ball contains `point` given in another frame.
+	///
+	/// Transforms `point` into the frame of the ball by applying `rotation` followed by
+	/// `translation` before testing containment. The ball itself is left untransformed, so
+	/// `rotation` is not required to be orthogonal.
+	#[must_use]
+	pub fn contains_transformed(
+		&self,
+		point: &OPoint<T, D>,
+		rotation: &OMatrix<T, D, D>,
+		translation: &OVector<T, D>,
+	) -> bool
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		self.contains(&OPoint::from(rotation * &point.coords + translation))
+	}
+	/// Whether ball contains `point` with hysteresis given its `previously_inside` state.
+	///
+	/// Implements a Schmitt trigger with two thresholds about the surface to stabilize decisions
+	/// of points oscillating near the surface:
+	///
+	///   * If `previously_inside` is `false`, `point` has to enter the ball by at least
+	///     `enter_epsilon` to be contained, that is its distance to the center has to be at most
+	///     the radius minus `enter_epsilon`.
+	///   * If `previously_inside` is `true`, `point` has to exit the ball by more than
+	///     `exit_epsilon` to not be contained anymore, that is its distance to the center has to
+	///     be larger than the radius plus `exit_epsilon`.
+	///
+	/// Between both thresholds, the previous state is retained.
+	#[must_use]
+	pub fn contains_hysteretic(
+		&self,
+		point: &OPoint<T, D>,
+		enter_epsilon: T,
+		exit_epsilon: T,
+		previously_inside: bool,
+	) -> bool {
+		let distance = (point - &self.center).norm();
+		let radius = self.radius_squared.clone().sqrt();
+		if previously_inside {
+			distance <= radius + exit_epsilon
+		} else {
+			distance <= radius - enter_epsilon
+		}
+	}
+	/// Returns containment mask of `points` with one element per point.
+	///
+	/// The `i`-th element is whether the `i`-th point is contained by ball.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn contains_mask<'a>(
+		&self,
+		points: impl IntoIterator<Item = &'a OPoint<T, D>>,
+	) -> Vec<bool> {
+		points
+			.into_iter()
+			.map(|point| self.contains(point))
+			.collect()
+	}
+	/// Whether ball contains all `points`.
+	///
+	/// Tests [`Enclosing::contains()`] for each point and short-circuits on the first one not
+	/// contained. This is `true` if there are no points.
+	#[must_use]
+	pub fn contains_all<'a>(&self, points: impl IntoIterator<Item = &'a OPoint<T, D>>) -> bool {
+		points.into_iter().all(|point| self.contains(point))
+	}
+	/// Returns number of `points` on the surface within absolute `epsilon`.
+	///
+	/// Counts the points whose [`Self::distance_to_surface()`] is at most `epsilon`. A minimum ball
+	/// has at least two points on its surface unless it encloses less than two points.
+	#[must_use]
+	pub fn count_on_surface<'a>(
+		&self,
+		points: impl IntoIterator<Item = &'a OPoint<T, D>>,
+		epsilon: T,
+	) -> usize {
+		points
+			.into_iter()
+			.filter(move |point| self.distance_to_surface(point) <= epsilon)
+			.count()
+	}
+	/// Returns point of `points` farthest from the center and its distance or `None` if empty.
+	///
+	/// The first of equally distant points is returned. Compared to the radius, the distance
+	/// estimates how tight the ball bounds `points`.
+	#[must_use]
+	#[allow(clippy::type_complexity)]
+	pub fn farthest_point<'a>(
+		&self,
+		points: impl IntoIterator<Item = &'a OPoint<T, D>>,
+	) -> Option<(&'a OPoint<T, D>, T)> {
+		self.extreme_point(points, |norm_squared, extreme| norm_squared > extreme)
+	}
+	/// Returns point of `points` nearest to the center and its distance or `None` if empty.
+	///
+	/// The first of equally distant points is returned.
+	#[must_use]
+	#[allow(clippy::type_complexity)]
+	pub fn nearest_point<'a>(
+		&self,
+		points: impl IntoIterator<Item = &'a OPoint<T, D>>,
+	) -> Option<(&'a OPoint<T, D>, T)> {
+		self.extreme_point(points, |norm_squared, extreme| norm_squared < extreme)
+	}
+	/// Returns point of `points` whose squared distance to the center is preferred by `prefer` over
+	/// the one of all other points.
+	///
+	/// Helper for [`Self::farthest_point()`] and [`Self::nearest_point()`].
+	#[allow(clippy::type_complexity)]
+	fn extreme_point<'a>(
+		&self,
+		points: impl IntoIterator<Item = &'a OPoint<T, D>>,
+		prefer: impl Fn(&T, &T) -> bool,
+	) -> Option<(&'a OPoint<T, D>, T)> {
+		points
+			.into_iter()
+			.map(|point| (point, (point - &self.center).norm_squared()))
+			.reduce(|extreme, candidate| {
+				if prefer(&candidate.1, &extreme.1) {
+					candidate
+				} else {
+					extreme
+				}
+			})
+			.map(|(point, norm_squared)| (point, norm_squared.sqrt()))
+	}
+	/// Returns pair of `points` on the surface most nearly antipodal or `None` if there are less
+	/// than two.
+	///
+	/// Considers the `points` located on the surface as by [`Self::locate()`] and returns the pair
+	/// whose midpoint is closest to the center. If the ball has exactly two support points, they
+	/// form its diameter, e.g., the principal axis of a point cloud's bounding ball.
+	#[cfg(feature = "std")]
+	#[must_use]
+	#[allow(clippy::type_complexity)]
+	pub fn antipodal_pair<'a>(
+		&self,
+		points: impl IntoIterator<Item = &'a OPoint<T, D>>,
+	) -> Option<(&'a OPoint<T, D>, &'a OPoint<T, D>)> {
+		let surface = points
+			.into_iter()
+			.filter(|point| self.locate(point) == Location::Surface)
+			.collect::<Vec<_>>();
+		let center = self.center.coords.clone() * (T::one() + T::one());
+		let mut pair = None;
+		let mut min_norm_squared = None;
+		for (index, &a) in surface.iter().enumerate() {
+			for &b in &surface[index + 1..] {
+				// Distance of doubled midpoint to doubled center.
+				let norm_squared = (&a.coords + &b.coords - &center).norm_squared();
+				if min_norm_squared
+					.as_ref()
+					.map_or(true, |min_norm_squared| norm_squared < *min_norm_squared)
+				{
+					min_norm_squared = Some(norm_squared);
+					pair = Some((a, b));
+				}
+			}
+		}
+		pair
+	}
+	/// Returns signed distance of `point` to the surface, negative inside and positive outside.
+	///
+	/// This is the signed distance function (SDF) of the ball.
+	#[must_use]
+	pub fn signed_distance(&self, point: &OPoint<T, D>) -> T {
+		self.signed_distance_with_radius(point, &self.radius())
+	}
+	/// Same as [`Self::signed_distance()`] with precomputed `radius`.
+	pub(crate) fn signed_distance_with_radius(&self, point: &OPoint<T, D>, radius: &T) -> T {
+		(point - &self.center).norm() - radius.clone()
+	}
+	/// Returns distance of `point` to the surface from either side.
+	///
+	/// Absolute value of [`Self::signed_distance()`].
+	#[must_use]
+	pub fn distance_to_surface(&self, point: &OPoint<T, D>) -> T {
+		self.signed_distance(point).abs()
+	}
+	/// Returns point on the surface closest to `point`.
+	///
+	/// Moves `point` along the ray from the center through `point` onto the surface. If `point`
+	/// coincides with the center, the direction is undefined and the surface point along the
+	/// first axis is returned instead.
+	#[must_use]
+	#[inline]
+	pub fn project_to_surface(&self, point: &OPoint<T, D>) -> OPoint<T, D> {
+		self.project_to_surface_with_radius(point, &self.radius())
+	}
+	/// Same as [`Self::project_to_surface()`] with precomputed `radius`.
+	pub(crate) fn project_to_surface_with_radius(
+		&self,
+		point: &OPoint<T, D>,
+		radius: &T,
+	) -> OPoint<T, D> {
+		(point - &self.center).try_normalize(T::zero()).map_or_else(
+			|| {
+				let mut point = self.center.clone();
+				point[0] += radius.clone();
+				point
+			},
+			|direction| &self.center + direction * radius.clone(),
+		)
+	}
+	/// Returns random point uniformly distributed on the surface.
+	///
+	/// Normalizes a vector of standard normally distributed coordinates to a uniformly
+	/// distributed direction which is scaled by the radius. The coordinates are sampled by the
+	/// Box-Muller transform.
+	#[cfg(feature = "rand")]
+	#[must_use]
+	pub fn sample_surface(&self, rng: &mut impl Rng) -> OPoint<T, D> {
+		let mut uniform = || T::from_subset(&(1.0 - rng.gen::<f64>()));
+		let direction = loop {
+			let mut vector = OVector::<T, D>::zeros();
+			for axis in (0..D::USIZE).step_by(2) {
+				let radius = (-(T::one() + T::one()) * uniform().ln()).sqrt();
+				let (sin, cos) = (T::two_pi() * uniform()).sin_cos();
+				vector[axis] = radius.clone() * cos;
+				if axis + 1 < D::USIZE {
+					vector[axis + 1] = radius * sin;
+				}
+			}
+			if let Some(direction) = vector.try_normalize(T::zero()) {
+				break direction;
+			}
+		};
+		&self.center + direction * self.radius()
+	}
+	/// Returns random point uniformly distributed in the interior.
+	///
+	/// Scales a point of [`Self::sample_surface()`] toward the center by `U^(1/D)` for a
+	/// uniformly distributed `U` which accounts for the volume growing with the `D`-th power of
+	/// the radius.
+	///
+	/// # Panics
+	///
+	/// Panics if dimension `D` is not representable in `T`.
+	#[cfg(feature = "rand")]
+	#[must_use]
+	pub fn sample_interior(&self, rng: &mut impl Rng) -> OPoint<T, D> {
+		let point = self.sample_surface(rng);
+		let scale =
+			T::from_subset(&rng.gen::<f64>()).powf(T::one() / T::from_usize(D::USIZE).unwrap());
+		&self.center + (point - &self.center) * scale
+	}
+	/// Returns power distance of `point` to ball, negative inside, zero on, and positive outside.
+	///
+	/// This is `(point - center).norm_squared() - radius_squared` as used in power diagrams.
+	#[must_use]
+	pub fn power_distance(&self, point: &OPoint<T, D>) -> T {
+		(point - &self.center).norm_squared() - self.radius_squared.clone()
+	}
+	/// Returns coefficients `(a, b, c)` of sphere `a * x.norm_squared() + b.dot(x) + c = 0`.
+	///
+	/// This is the general form with `a = 1`, `b = -2 * center`, and
+	/// `c = center.norm_squared() - radius_squared` as expected by solvers consuming quadric
+	/// coefficients. Evaluating its left-hand side at `x` gives [`Self::power_distance()`].
+	/// The inverse is [`Self::from_general_form()`].
+	#[must_use]
+	pub fn general_form(&self) -> (T, OVector<T, D>, T) {
+		let two = T::one() + T::one();
+		(
+			T::one(),
+			&self.center.coords * -two,
+			self.center.coords.norm_squared() - self.radius_squared.clone(),
+		)
+	}
+	/// Returns signed distances of `points` to the surface, positive inside and negative outside.
+	///
+	/// The `i`-th clearance is the negated [`Self::signed_distance()`] of the `i`-th point.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn clearances<'a>(&self, points: impl IntoIterator<Item = &'a OPoint<T, D>>) -> Vec<T> {
+		points
+			.into_iter()
+			.map(|point| -self.signed_distance(point))
+			.collect()
+	}
+	/// Whether ball intersects `other` ball including touching surfaces.
+	///
+	/// Same as [`Self::intersection_kind()`] not being [`Intersection::Disjoint`].
+	#[must_use]
+	#[inline]
+	pub fn intersects(&self, other: &Self) -> bool {
+		self.intersection_kind(other) != Intersection::Disjoint
+	}
+	/// Whether ball contains `other` ball, i.e., every point of `other` is inside or on the surface.
+	///
+	/// Tests whether the distance `d + r₂` of the far side of `other` to the center is at most the
+	/// radius `r₁` within the same relative tolerance as [`Enclosing::contains()`], i.e.,
+	/// `(d + r₂)² * (1 - epsilon) <= r₁²`. This is compared in squared form as `r₂ <= r` and
+	/// `d² <= (r - r₂)²` with the tolerated radius `r = r₁ / √(1 - epsilon)`, so the distance is
+	/// never square-rooted and `other` ball of larger radius is rejected before any square root.
+	/// Unlike [`Self::intersects()`], this is asymmetric. A ball contains itself and `other` ball
+	/// of zero radius reduces to containing its center. Every ball contains an empty `other` ball,
+	/// see [`Self::empty()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `other` ball is infinite.
+	#[must_use]
+	pub fn contains_ball(&self, other: &Self) -> bool {
+		if other.is_empty() {
+			return true;
+		}
+		let distance_squared = (&other.center - &self.center).norm_squared();
 		assert!(
-			self.radius_squared.is_finite() && other.radius_squared.is_finite(),
+			distance_squared.is_finite() && other.radius_squared.is_finite(),
 			"infinite ball"
 		);
-		self.radius_squared == other.radius_squared
+		let radius_squared = self.radius_squared.clone() / (T::one() - T::default_epsilon().sqrt());
+		if other.radius_squared > radius_squared {
+			return false;
+		}
+		let margin = radius_squared.sqrt() - other.radius();
+		distance_squared <= margin.clone() * margin
+	}
+	/// Whether ball contains `ellipsoid` by containing its bounding ball.
+	///
+	/// The bounding ball is centered at the ellipsoid's center with its largest semi-axis as
+	/// radius, i.e., the inverse square root of the smallest eigenvalue of its shape matrix as
+	/// minimizer of [`Ellipsoid::quadratic_form()`] over unit directions. Then, this is the same
+	/// as [`Self::contains_ball()`]. The test is sufficient but conservative as it fails for
+	/// ellipsoids whose bounding ball pokes out in directions of shorter semi-axes.
+	///
+	/// # Panics
+	///
+	/// Panics if `ellipsoid` is infinite.
+	#[must_use]
+	pub fn contains_ellipsoid(&self, ellipsoid: &Ellipsoid<T, D>) -> bool
+	where
+		D: DimSub<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<T, DimDiff<D, U1>>,
+	{
+		let eigenvalue = ellipsoid.shape.symmetric_eigenvalues().min();
+		self.contains_ball(&Self {
+			center: ellipsoid.center.clone(),
+			radius_squared: T::one() / eigenvalue,
+		})
+	}
+	/// Classifies intersection of ball with `other` ball.
+	///
+	/// Compares the center distance `d` with the sum and difference of the radii `r₁` and `r₂`
+	/// without taking square roots by squaring `d² - r₁² - r₂²` against `4r₁²r₂²`. Touching is
+	/// decided within the same relative tolerance of `d²` as [`Enclosing::contains()`] of a point
+	/// on the surface, i.e., by `2 * epsilon * (d² - r₁² - r₂²) * d²` as the first-order change of
+	/// the squared comparison. Degenerate balls of zero radius are points and contained if on the
+	/// surface of the other ball. Empty balls are disjoint with every ball, see [`Self::empty()`].
+	#[must_use]
+	pub fn intersection_kind(&self, other: &Self) -> Intersection {
+		if self.is_empty() || other.is_empty() {
+			return Intersection::Disjoint;
+		}
+		let distance_squared = (&self.center - &other.center).norm_squared();
+		let difference =
+			distance_squared.clone() - self.radius_squared.clone() - other.radius_squared.clone();
+		let two = T::one() + T::one();
+		let product =
+			two.clone() * two.clone() * self.radius_squared.clone() * other.radius_squared.clone();
+		let deviation = difference.clone() * difference.clone() - product;
+		let tolerance = two * T::default_epsilon().sqrt() * difference.clone() * distance_squared;
+		if difference <= T::zero() && deviation >= T::zero() {
+			// d ≤ |r₁ - r₂|
+			Intersection::Contained
+		} else if difference > T::zero() && deviation.clone().abs() <= tolerance {
+			// d ≈ r₁ + r₂
+			Intersection::Touching
+		} else if difference > T::zero() && deviation > T::zero() {
+			// d > r₁ + r₂
+			Intersection::Disjoint
+		} else {
+			Intersection::Overlapping
+		}
+	}
+	/// Returns minimum and maximum corner of axis-aligned bounding box of ball.
+	///
+	/// The corners are `center ∓ radius` along each axis.
+	#[must_use]
+	#[inline]
+	pub fn aabb(&self) -> (OPoint<T, D>, OPoint<T, D>) {
+		self.aabb_with_radius(&self.radius())
+	}
+	/// Same as [`Self::aabb()`] with precomputed `radius`.
+	pub(crate) fn aabb_with_radius(&self, radius: &T) -> (OPoint<T, D>, OPoint<T, D>) {
+		(
+			self.center.map(|coordinate| coordinate - radius.clone()),
+			self.center.map(|coordinate| coordinate + radius.clone()),
+		)
+	}
+	/// Whether axis-aligned bounding box of ball contains `point`.
+	///
+	/// Cheap rejection test before [`Enclosing::contains()`] as points outside the box are
+	/// outside the ball, whereas points inside the box might still be outside the ball.
+	#[must_use]
+	pub fn aabb_contains_point(&self, point: &OPoint<T, D>) -> bool {
+		let radius = self.radius();
+		(point - &self.center).amax() <= radius
+	}
+	/// Whether ball contains axis-aligned box from `min` to `max`.
+	///
+	/// Tests whether the box corner farthest from the center is contained.
+	#[must_use]
+	pub fn contains_box(&self, min: &OPoint<T, D>, max: &OPoint<T, D>) -> bool {
+		let corner = OPoint::from(OVector::<T, D>::from_fn(|row, _column| {
+			let min = min[row].clone();
+			let max = max[row].clone();
+			let center = self.center[row].clone();
+			if center.clone() - min.clone() >= max.clone() - center {
+				min
+			} else {
+				max
+			}
+		}));
+		self.contains(&corner)
 	}
-}
 
-impl<T: RealField, D: DimName> Eq for Ball<T, D> where DefaultAllocator: Allocator<T, D> {}
+	/// Returns minimum radius squared of ball with fixed `center` covering `points`.
+	///
+	/// This is the maximum squared distance of `points` to `center` or zero if there are none. It
+	/// is far cheaper than [`Enclosing::enclosing_points()`] if the center is known beforehand.
+	#[must_use]
+	pub fn radius_squared_covering<'a>(
+		center: &OPoint<T, D>,
+		points: impl IntoIterator<Item = &'a OPoint<T, D>>,
+	) -> T {
+		points
+			.into_iter()
+			.map(|point| (point - center).norm_squared())
+			.fold(T::zero(), T::max)
+	}
+	/// Returns minimum ball with fixed `center` enclosing `points`.
+	///
+	/// The radius is the maximum distance of `points` to `center`, see
+	/// [`Self::radius_squared_covering()`]. The ball is of zero radius if there are no points.
+	#[must_use]
+	pub fn enclosing_points_about(
+		center: &OPoint<T, D>,
+		points: impl IntoIterator<Item = OPoint<T, D>>,
+	) -> Self {
+		Self {
+			center: center.clone(),
+			radius_squared: points
+				.into_iter()
+				.map(|point| (point - center).norm_squared())
+				.fold(T::zero(), T::max),
+		}
+	}
+	/// Returns index pairs `(i, j)` with `i < j` of all intersecting `balls` in ascending order.
+	///
+	/// Tests [`Self::intersects()`] by sort and sweep along the first axis. The balls are sorted by
+	/// the lower end of their extent along this axis and only the ones whose extents overlap are
+	/// tested against each other. This avoids the *O*(*n*²) tests of all pairs unless most extents
+	/// overlap.
+	///
+	/// # Panics
+	///
+	/// Panics on infinite balls.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn intersection_matrix(balls: &[Self]) -> Vec<(usize, usize)> {
+		let extent = |ball: &Self| {
+			let center = ball.center.coords.get(0).cloned().unwrap_or_else(T::zero);
+			let radius = ball.radius_squared.clone().sqrt();
+			(center.clone() - radius.clone(), center + radius)
+		};
+		let mut extents = balls.iter().map(extent).enumerate().collect::<Vec<_>>();
+		extents.sort_unstable_by(|(_i, (a, _a)), (_j, (b, _b))| {
+			a.partial_cmp(b).expect("infinite ball")
+		});
+		let mut active = Vec::<(usize, T)>::new();
+		let mut pairs = Vec::new();
+		for (index, (lower, upper)) in extents {
+			active.retain(|(_index, active_upper)| *active_upper >= lower);
+			pairs.extend(
+				active
+					.iter()
+					.filter(|&&(other, _)| balls[index].intersects(&balls[other]))
+					.map(|&(other, _)| (index.min(other), index.max(other))),
+			);
+			active.push((index, upper));
+		}
+		pairs.sort_unstable();
+		pairs
+	}
+	/// Grows ball minimally to enclose itself and `point` unless it already contains `point`.
+	///
+	/// The center moves along the line toward `point` such that the far side of the ball stays on
+	/// the surface. This is the growing step of Ritter's algorithm. Growing a ball by streamed
+	/// points is cheap but in general not minimal over the whole point set unlike
+	/// [`Enclosing::enclosing_points()`]. A ball of zero radius grows to the ball having the two
+	/// points as diameter. An empty ball becomes a ball of zero radius at `point`, see
+	/// [`Self::empty()`].
+	pub fn expand_to_contain(&mut self, point: &OPoint<T, D>) {
+		if self.is_empty() {
+			self.center = point.clone();
+			self.radius_squared = T::zero();
+			return;
+		}
+		let norm_squared = (point - &self.center).norm_squared();
+		if norm_squared > self.radius_squared {
+			let distance = norm_squared.sqrt();
+			let grown = (self.radius() + distance.clone()) / (T::one() + T::one());
+			self.center += (point - &self.center) * ((distance.clone() - grown.clone()) / distance);
+			self.radius_squared = grown.clone() * grown;
+		}
+	}
+	/// Returns minimum ball enclosing ball and `other` ball.
+	///
+	/// If one ball contains the other, including coincident centers, the larger one is returned.
+	/// Otherwise, the diameter spans from the far side of one ball to the far side of the other, so
+	/// the radius is `(d + r₁ + r₂) / 2` for center distance `d` and the center lies on the line
+	/// between both centers. Empty balls are the identity, see [`Self::empty()`].
+	#[must_use]
+	pub fn merged(&self, other: &Self) -> Self {
+		if other.is_empty() {
+			return self.clone();
+		}
+		if self.is_empty() {
+			return other.clone();
+		}
+		if self.intersection_kind(other) == Intersection::Contained {
+			return if self.radius_squared >= other.radius_squared {
+				self.clone()
+			} else {
+				other.clone()
+			};
+		}
+		let vector = &other.center - &self.center;
+		let distance = vector.norm();
+		let radius = self.radius();
+		let merged_radius =
+			(distance.clone() + radius.clone() + other.radius()) / (T::one() + T::one());
+		Self {
+			center: &self.center + vector * ((merged_radius.clone() - radius) / distance),
+			radius_squared: merged_radius.clone() * merged_radius,
+		}
+	}
+	/// Returns ball translated by `offset`.
+	///
+	/// Adds `offset` to the center whereas the radius is unchanged.
+	#[must_use]
+	pub fn translated(&self, offset: &OVector<T, D>) -> Self {
+		Self {
+			center: &self.center + offset,
+			radius_squared: self.radius_squared.clone(),
+		}
+	}
+	/// Returns ball scaled by `factor` about its center.
+	///
+	/// Multiplies the radius by `|factor|` whereas the center is unchanged.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `factor` is not finite.
+	#[must_use]
+	pub fn scaled(&self, factor: T) -> Self {
+		debug_assert!(factor.is_finite(), "infinite factor");
+		Self {
+			center: self.center.clone(),
+			radius_squared: self.radius_squared.clone() * factor.clone() * factor,
+		}
+	}
+	/// Returns ball scaled by `factor` about `pivot`.
+	///
+	/// Like [`Self::scaled()`] but also moves the center relative to `pivot`, that is the center
+	/// is mapped to `pivot + (center - pivot) * factor`. A negative `factor` mirrors the center
+	/// through `pivot`.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `factor` is not finite.
+	#[must_use]
+	pub fn scaled_about(&self, pivot: &OPoint<T, D>, factor: T) -> Self {
+		let ball = self.scaled(factor.clone());
+		Self {
+			center: pivot + (ball.center - pivot) * factor,
+			radius_squared: ball.radius_squared,
+		}
+	}
+	/// Returns ball grown by absolute `margin` about its center.
+	///
+	/// Adds `margin` to the radius whereas the center is unchanged. A negative `margin` shrinks the
+	/// ball, see [`Self::shrink_by()`].
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `margin` is not finite.
+	#[must_use]
+	pub fn grow_by(&self, margin: T) -> Self {
+		let (ball, _radius) = self.grow_by_with_radius(margin, &self.radius());
+		ball
+	}
+	/// Same as [`Self::grow_by()`] with precomputed `radius` returning the grown radius as well.
+	pub(crate) fn grow_by_with_radius(&self, margin: T, radius: &T) -> (Self, T) {
+		debug_assert!(margin.is_finite(), "infinite margin");
+		let radius = (radius.clone() + margin).max(T::zero());
+		let ball = Self {
+			center: self.center.clone(),
+			radius_squared: radius.clone() * radius.clone(),
+		};
+		(ball, radius)
+	}
+	/// Returns ball shrunk by absolute `margin` about its center.
+	///
+	/// Subtracts `margin` from the radius whereas the center is unchanged. The radius is clamped at
+	/// zero if `margin` exceeds it, i.e., the ball degenerates to its center.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `margin` is not finite.
+	#[must_use]
+	pub fn shrink_by(&self, margin: T) -> Self {
+		self.grow_by(-margin)
+	}
+	/// Returns ball linearly interpolated between ball and `other` ball by `t`.
+	///
+	/// Interpolates the center and the radius, not the radius squared, so `t` of zero yields
+	/// ball, one yields `other`, and one half yields the ball with averaged center and radius. A
+	/// `t` outside of zero and one extrapolates with the radius clamped at zero.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `t` is not finite.
+	#[must_use]
+	pub fn lerp(&self, other: &Self, t: T) -> Self {
+		let (ball, _radius) = self.lerp_with_radii(other, t, &self.radius(), &other.radius());
+		ball
+	}
+	/// Same as [`Self::lerp()`] with precomputed `radius` and `other_radius` returning the
+	/// interpolated radius as well.
+	pub(crate) fn lerp_with_radii(
+		&self,
+		other: &Self,
+		t: T,
+		radius: &T,
+		other_radius: &T,
+	) -> (Self, T) {
+		debug_assert!(t.is_finite(), "infinite parameter");
+		let radius =
+			(radius.clone() + (other_radius.clone() - radius.clone()) * t.clone()).max(T::zero());
+		let ball = Self {
+			center: self.center.lerp(&other.center, t),
+			radius_squared: radius.clone() * radius.clone(),
+		};
+		(ball, radius)
+	}
+	/// Returns minimum ball enclosing `balls`.
+	///
+	/// Implements Welzl's recursive algorithm generalized to balls with move-to-front heuristic
+	/// like [`Enclosing::enclosing_points()`]. A ball is considered enclosed if the distance of
+	/// its far side to the center is at most the radius within the same relative tolerance as
+	/// [`Enclosing::contains()`]. Instead of circumscribing bounding points, the bounding balls
+	/// are tangent to the surface from inside. As Welzl's algorithm is not guaranteed to find the
+	/// minimum for balls of different radii in degenerate configurations, it retries with the
+	/// permuted `balls` and eventually falls back to successively [merging](Self::merged) them,
+	/// which always encloses them but not necessarily minimally.
+	///
+	/// # Panics
+	///
+	/// Panics if `balls` is empty.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn enclosing_balls(balls: &mut impl Deque<Self>) -> Self
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		assert!(!balls.is_empty(), "empty ball set");
+		let mut bounds = Vec::with_capacity(D::USIZE + 1);
+		(0..=D::USIZE)
+			.find_map(|_| {
+				maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+					Self::enclosing_balls_with_bounds(balls, &mut bounds)
+				})
+			})
+			.unwrap_or_else(|| {
+				let mut merged = None;
+				deque::for_each(balls, |ball| {
+					merged = Some(
+						merged
+							.take()
+							.map_or_else(|| ball.clone(), |merged: Self| merged.merged(ball)),
+					);
+				});
+				merged.unwrap()
+			})
+	}
+	/// Returns minimum power ball enclosing `points` with squared weights.
+	///
+	/// Each element of `points` is a point `pᵢ` with a squared weight `wᵢ`, e.g., the squared
+	/// radius of its uncertainty. The returned ball of center `c` minimizes its radius squared
+	/// `R²` subject to the power distances `|c - pᵢ|² - wᵢ ≤ R²`. Weights may be negative. For
+	/// equal weights `w`, this is the minimum ball enclosing the points with its radius squared
+	/// reduced by `w`, so it is negative if `w` exceeds the radius squared of the latter.
+	///
+	/// Implements Welzl's recursive algorithm with move-to-front heuristic like
+	/// [`Enclosing::enclosing_points()`] circumscribing the bounds of equal power distance instead
+	/// of equal distance. The power distance is compared within the same relative tolerance as
+	/// [`Enclosing::contains()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn enclosing_weighted_points(points: &mut impl Deque<(OPoint<T, D>, T)>) -> Self
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		assert!(!points.is_empty(), "empty point set");
+		let mut bounds = Vec::with_capacity(D::USIZE + 1);
+		(0..=D::USIZE)
+			.find_map(|_| {
+				maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+					Self::enclosing_weighted_points_with_bounds(points, &mut bounds)
+				})
+			})
+			.expect("numerical instability")
+	}
+	/// Returns ball with the total volume of `balls` centered at their volume-weighted centroid.
+	///
+	/// As the volume is proportional to the radius to the power of `D`, the radius is the `D`-th
+	/// root of the sum of the radii to the power of `D`. This is a representative ball for
+	/// downsampling and does not guarantee to enclose `balls`. Returns `None` if `balls` is empty
+	/// or has no volume.
+	///
+	/// # Panics
+	///
+	/// Panics if dimension `D` is not representable in `T`.
+	#[must_use]
+	pub fn volume_preserving_merge(balls: &[Self]) -> Option<Self> {
+		let half = T::from_usize(D::USIZE).unwrap() / (T::one() + T::one());
+		let (sum, volume) = balls.iter().fold(
+			(OVector::<T, D>::zeros(), T::zero()),
+			|(sum, volume), ball| {
+				let weight = ball.radius_squared.clone().powf(half.clone());
+				(sum + &ball.center.coords * weight.clone(), volume + weight)
+			},
+		);
+		(volume > T::zero()).then(|| Self {
+			center: OPoint::from(sum / volume.clone()),
+			radius_squared: volume.powf(T::one() / half),
+		})
+	}
+	/// Returns ball enclosing `quantile` of `points` ignoring the farthest ones.
+	///
+	/// Two centering modes are supported:
+	///
+	///   * If `centroid_mode` is `true`, the center is the centroid of `points`.
+	///   * Otherwise, the center is the one of the minimum ball enclosing all `points` as returned
+	///     by [`Enclosing::enclosing_points()`].
+	///
+	/// About this center, the radius is the nearest-rank `quantile` of the distances to `points`,
+	/// that is the smallest radius enclosing at least `quantile` of `points`.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or if `quantile` is not within `(0, 1]`.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn enclosing_points_quantile(
+		points: &[OPoint<T, D>],
+		centroid_mode: bool,
+		quantile: T,
+	) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert!(!points.is_empty(), "empty point set");
+		assert!(
+			quantile > T::zero() && quantile <= T::one(),
+			"quantile out of range"
+		);
+		let center = if centroid_mode {
+			let sum = points
+				.iter()
+				.fold(OVector::<T, D>::zeros(), |sum, point| sum + &point.coords);
+			OPoint::from(sum / T::from_usize(points.len()).unwrap())
+		} else {
+			Self::enclosing_points(&mut points.iter().cloned().collect::<VecDeque<_>>()).center
+		};
+		let mut distances = points
+			.iter()
+			.map(|point| (point - &center).norm_squared())
+			.collect::<Vec<_>>();
+		let count = T::from_usize(points.len()).unwrap() * quantile;
+		let rank = (1..points.len())
+			.find(|&rank| T::from_usize(rank).unwrap() >= count)
+			.unwrap_or(points.len());
+		let (_nearer, radius_squared, _farther) = distances
+			.select_nth_unstable_by(rank - 1, |a, b| a.partial_cmp(b).expect("infinite point"));
+		Self {
+			center,
+			radius_squared: radius_squared.clone(),
+		}
+	}
 
-impl<T: RealField, D: DimName> PartialOrd for Ball<T, D>
-where
-	DefaultAllocator: Allocator<T, D>,
-{
-	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-		Some(self.cmp(other))
+	/// Returns minimum ball and approximate ball enclosing `points` for comparison.
+	///
+	/// Returns `(exact, approximate)` where `exact` is computed by
+	/// [`Enclosing::enclosing_points()`] and `approximate` by [Ritter's algorithm] which is
+	/// cheaper but only guarantees enclosure. This allows to measure the tightness gap on actual
+	/// data before switching to the approximate method.
+	///
+	/// [Ritter's algorithm]: https://doi.org/10.1016/B978-0-08-050753-8.50063-2
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	pub fn enclosing_points_compare(points: &mut impl Deque<OPoint<T, D>>) -> (Self, Self)
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		let approximate = Self::ritter(points);
+		(Self::enclosing_points(points), approximate)
 	}
-}
 
-impl<T: RealField, D: DimName> Ord for Ball<T, D>
-where
-	DefaultAllocator: Allocator<T, D>,
-{
-	fn cmp(&self, other: &Self) -> Ordering {
-		self.radius_squared
-			.partial_cmp(&other.radius_squared)
-			.expect("infinite ball")
+	/// Returns minimum ball enclosing `points` with relative containment `epsilon`.
+	///
+	/// Same as [`Enclosing::enclosing_points()`] but tests containment by
+	/// [`Self::contains_with_tolerance()`] with `epsilon` instead of `T::default_epsilon().sqrt()`.
+	/// As the recursion only circumscribes a new ball for points not contained by the current one,
+	/// a smaller `epsilon` yields a ball whose surface deviates less from the outermost points at
+	/// the cost of more recursion steps and retries, whereas a larger `epsilon` tolerates noisy
+	/// `points` at the cost of enclosing them less tightly. Collinear points are not detected
+	/// beforehand.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	pub fn enclosing_points_with_tolerance(
+		points: &mut impl Deque<OPoint<T, D>>,
+		epsilon: T,
+	) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		enclosing_points_by(points, &move |ball: &Self, point| {
+			ball.contains_with_tolerance(point, epsilon.clone())
+		})
+	}
+	/// Returns minimum ball enclosing `points` with containment epsilon adapted to their magnitude.
+	///
+	/// The relative epsilon `T::default_epsilon().sqrt()` of [`Enclosing::contains()`] assumes the
+	/// rounding errors of coordinates to be small compared to the radius. This breaks down when the
+	/// magnitude `M` of the largest absolute coordinate exceeds the range `L` of coordinates by
+	/// orders of magnitude, e.g., for points around `1e6` with a radius of `1e-3`, as the
+	/// inaccuracies of computing circumscribed balls grow with `T::default_epsilon() * M / L`.
+	/// Hence, the relative epsilon is scaled to
+	/// `T::default_epsilon().sqrt().max(T::default_epsilon() * M / L * F)` where `F = (D + 1)²`
+	/// accounts for the conditioning of circumscribed balls.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	pub fn enclosing_points_auto_epsilon(points: &mut impl Deque<OPoint<T, D>>) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		let (mut magnitude, mut min, mut max) = (T::zero(), None, None);
+		deque::for_each(points, |point| {
+			magnitude = point
+				.iter()
+				.fold(magnitude.clone(), |magnitude, coordinate| {
+					magnitude.max(coordinate.clone().abs())
+				});
+			min = Some(
+				min.take()
+					.map_or_else(|| point.clone(), |min: OPoint<T, D>| min.inf(point)),
+			);
+			max = Some(
+				max.take()
+					.map_or_else(|| point.clone(), |max: OPoint<T, D>| max.sup(point)),
+			);
+		});
+		let range = min
+			.zip(max)
+			.map_or_else(T::zero, |(min, max)| (max - min).amax());
+		let bounds = T::from_usize((D::USIZE + 1).pow(2)).unwrap();
+		let epsilon = T::default_epsilon().sqrt();
+		let epsilon = if range > T::zero() {
+			epsilon.max(T::default_epsilon() * magnitude / range * bounds)
+		} else {
+			epsilon
+		};
+		Self::enclosing_points_with_tolerance(points, epsilon)
+	}
+	/// Returns smallest of minimum balls enclosing `samples` random permutations of `points`.
+	///
+	/// Each sample permutes its own copy of `points` before invoking
+	/// [`Enclosing::enclosing_points()`]. As samples are independent, they are computed in
+	/// parallel. This mitigates the order-dependent inaccuracies for degenerate `points`.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty, if `samples` is zero, or on numerical instability.
+	#[cfg(all(feature = "rayon", feature = "rand"))]
+	#[must_use]
+	pub fn enclosing_points_best_of_parallel(points: &[OPoint<T, D>], samples: usize) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+		OPoint<T, D>: Send + Sync,
+	{
+		(0..samples)
+			.into_par_iter()
+			.map(|_sample| {
+				let mut points = points.to_vec();
+				points.shuffle(&mut thread_rng());
+				Self::enclosing_points(&mut VecDeque::from(points))
+			})
+			.min_by(|a, b| {
+				a.radius_squared
+					.partial_cmp(&b.radius_squared)
+					.expect("infinite ball")
+			})
+			.expect("zero samples")
+	}
+	/// Returns minimum balls enclosing independent `sets` of points in parallel.
+	///
+	/// Invokes [`Enclosing::enclosing_points()`] for each set on the thread pool of [`rayon`] and
+	/// returns the balls in the order of `sets`. Each set is processed sequentially by a single
+	/// worker thread which spills its recursion stack over to the heap like any other thread.
+	///
+	/// # Panics
+	///
+	/// Panics if any set is empty or on numerical instability.
+	#[cfg(feature = "rayon")]
+	#[must_use]
+	pub fn enclosing_points_par<'a>(
+		sets: impl ParallelIterator<Item = &'a mut VecDeque<OPoint<T, D>>>,
+	) -> Vec<Self>
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+		OPoint<T, D>: Send,
+	{
+		sets.map(|points| Self::enclosing_points(points)).collect()
+	}
+	/// Returns minimum ball enclosing `points` with radius rounded up to a multiple of `radius_step`.
+	///
+	/// Rounding the radius up instead of to the nearest multiple preserves the enclosure of
+	/// `points` as the ball only grows about the same center.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty, if `radius_step` is not positive, or on numerical instability.
+	#[must_use]
+	pub fn enclosing_points_quantized_radius(
+		points: &mut impl Deque<OPoint<T, D>>,
+		radius_step: T,
+	) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert!(radius_step > T::zero(), "non-positive radius step");
+		let Self {
+			center,
+			radius_squared,
+		} = Self::enclosing_points(points);
+		let radius = (radius_squared.sqrt() / radius_step.clone()).ceil() * radius_step;
+		Self {
+			center,
+			radius_squared: radius.clone() * radius,
+		}
+	}
+	/// Returns approximate ball enclosing `points` within `1 + epsilon` of the minimum radius.
+	///
+	/// Implements the [Bădoiu–Clarkson] core-set iteration starting at the first point and
+	/// repeatedly moving the center a `1 / (i + 1)` fraction toward the current farthest point in
+	/// the `i`-th iteration. The radius is the distance of the final center to its farthest point.
+	///
+	/// After `⌈1 / epsilon²⌉` iterations, the radius is guaranteed to be within `1 + epsilon` of
+	/// the minimum radius independent of the dimension. Each iteration takes *O*(*dm*) time for
+	/// `m` points in `d` dimensions, whereas the expected time of
+	/// [`Enclosing::enclosing_points()`] grows factorially with the dimension. The iterations are
+	/// capped at `max_iters` trading the guarantee for time.
+	///
+	/// [Bădoiu–Clarkson]: https://doi.org/10.1016/j.comgeo.2007.04.002
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or infinite or if `epsilon` is not positive.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	pub fn approximate_enclosing_points(
+		points: &[OPoint<T, D>],
+		epsilon: T,
+		max_iters: usize,
+	) -> Self {
+		assert!(epsilon > T::zero(), "non-positive epsilon");
+		let mut center = points.first().expect("empty point set").clone();
+		let farthest = |center: &OPoint<T, D>| {
+			points
+				.iter()
+				.max_by(|a, b| {
+					let a = (*a - center).norm_squared();
+					let b = (*b - center).norm_squared();
+					a.partial_cmp(&b).expect("infinite point")
+				})
+				.unwrap()
+		};
+		let iters = (T::one() / (epsilon.clone() * epsilon)).ceil();
+		let iters = max_iters.min(
+			iters
+				.to_subset()
+				.map_or(usize::MAX, |iters: f64| iters as usize),
+		);
+		for i in 1..=iters {
+			let step = T::one() / T::from_usize(i + 1).unwrap();
+			center = &center + (farthest(&center) - &center) * step;
+		}
+		let radius_squared = (farthest(&center) - &center).norm_squared();
+		Self {
+			center,
+			radius_squared,
+		}
 	}
 }
 
-impl<T: RealField, D: DimName> Enclosing<T, D> for Ball<T, D>
+impl<T: RealField, D: DimName> Ball<T, D>
 where
 	DefaultAllocator: Allocator<T, D>,
 {
-	#[inline]
-	fn contains(&self, point: &OPoint<T, D>) -> bool {
+	/// Whether power distance of `point` with squared `weight` is at most the radius squared.
+	///
+	/// Relative epsilon `T::default_epsilon().sqrt()` of squared distance like [`Self::locate()`].
+	#[cfg(feature = "std")]
+	fn contains_weighted(&self, point: &OPoint<T, D>, weight: &T) -> bool {
 		let norm_squared = (point - &self.center).norm_squared();
 		assert!(norm_squared.is_finite(), "infinite point");
-		self.radius_squared.clone() / norm_squared >= T::one() - T::default_epsilon().sqrt()
+		let tolerance = norm_squared.clone() * T::default_epsilon().sqrt();
+		norm_squared - weight.clone() <= self.radius_squared.clone() + tolerance
 	}
-	fn with_bounds(bounds: &[OPoint<T, D>]) -> Option<Self>
+	/// Returns minimum power ball enclosing weighted `points` with weighted `bounds`.
+	///
+	/// Recursive helper for [`Self::enclosing_weighted_points()`].
+	#[cfg(feature = "std")]
+	fn enclosing_weighted_points_with_bounds(
+		points: &mut impl Deque<(OPoint<T, D>, T)>,
+		bounds: &mut Vec<(OPoint<T, D>, T)>,
+	) -> Option<Self>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let len = points.len();
+		// Take point from back unless bounds are full as it would get lost otherwise.
+		let point = if bounds.len() > D::USIZE {
+			None
+		} else {
+			points.pop_back()
+		};
+		if let Some(point) = point {
+			let ball = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+				// Branch with one point less.
+				Self::enclosing_weighted_points_with_bounds(points, bounds)
+			});
+			if let Some(ball) = ball.filter(|ball| ball.contains_weighted(&point.0, &point.1)) {
+				// Move point to back.
+				points.push_back(point);
+				debug_assert_eq!(points.len(), len, "deque length not conserved");
+				Some(ball)
+			} else {
+				// Move point to bounds.
+				bounds.push(point);
+				let ball = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+					// Branch with one point less and one bound more.
+					Self::enclosing_weighted_points_with_bounds(points, bounds)
+				});
+				// Move point to front.
+				points.push_front(bounds.pop().unwrap());
+				debug_assert_eq!(points.len(), len, "deque length not conserved");
+				ball
+			}
+		} else {
+			// Power ball with bounds of equal power distance.
+			Self::with_weighted_bounds(bounds)
+		}
+	}
+	/// Returns power ball with all weighted `bounds` of equal power distance or `None` if it does
+	/// not exist.
+	///
+	/// The center `c = p₀ + x` is in the affine hull of the points `pᵢ = p₀ + vᵢ` of the bounds of
+	/// squared weights `wᵢ`. Subtracting the conditions `|x - vᵢ|² - wᵢ = R²` from the one of the
+	/// first bound yields the linear system `2 vᵢ·x = |vᵢ|² - wᵢ + w₀` with `x = Σ λⱼ vⱼ`.
+	///
+	/// Helper for [`Self::enclosing_weighted_points()`].
+	#[cfg(feature = "std")]
+	fn with_weighted_bounds(bounds: &[(OPoint<T, D>, T)]) -> Option<Self>
 	where
 		DefaultAllocator: Allocator<T, D, D>,
 	{
 		let length = bounds.len().checked_sub(1).filter(|&len| len <= D::USIZE)?;
-		let points = OMatrix::<T, D, D>::from_fn(|row, column| {
+		let ((first, weight), bounds) = bounds.split_first()?;
+		let vectors = OMatrix::<T, D, D>::from_fn(|row, column| {
 			if column < length {
-				bounds[column + 1].coords[row].clone() - bounds[0].coords[row].clone()
+				bounds[column].0[row].clone() - first[row].clone()
 			} else {
 				T::zero()
 			}
 		});
-		let points = points.view((0, 0), (D::USIZE, length));
+		let vectors = vectors.view((0, 0), (D::USIZE, length));
 		let matrix = OMatrix::<T, D, D>::from_fn(|row, column| {
 			if row < length && column < length {
-				points.column(row).dot(&points.column(column)) * (T::one() + T::one())
+				vectors.column(row).dot(&vectors.column(column)) * (T::one() + T::one())
 			} else {
 				T::zero()
 			}
@@ -97,7 +1717,7 @@ where
 		let matrix = matrix.view((0, 0), (length, length));
 		let vector = OVector::<T, D>::from_fn(|row, _column| {
 			if row < length {
-				points.column(row).norm_squared()
+				vectors.column(row).norm_squared() - bounds[row].1.clone() + weight.clone()
 			} else {
 				T::zero()
 			}
@@ -106,15 +1726,507 @@ where
 		matrix.try_inverse().and_then(|matrix| {
 			let vector = matrix * vector;
 			let mut center = OVector::<T, D>::zeros();
-			for point in 0..length {
-				center += points.column(point) * vector[point].clone();
+			for bound in 0..length {
+				center += vectors.column(bound) * vector[bound].clone();
 			}
-			let radius_squared = center.norm_squared();
-			let center = &bounds[0] + &center;
+			let radius_squared = center.norm_squared() - weight.clone();
+			let center = first + center;
 			radius_squared.is_finite().then(|| Self {
 				center,
 				radius_squared,
 			})
 		})
 	}
+	/// Returns minimum ball enclosing `balls` with `bounds` tangent to the surface from inside.
+	///
+	/// Recursive helper for [`Self::enclosing_balls()`].
+	#[cfg(feature = "std")]
+	fn enclosing_balls_with_bounds(
+		balls: &mut impl Deque<Self>,
+		bounds: &mut Vec<Self>,
+	) -> Option<Self>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let len = balls.len();
+		// Take ball from back.
+		let ball = if bounds.len() > D::USIZE {
+			None
+		} else {
+			balls.pop_back()
+		};
+		if let Some(ball) = ball {
+			let enclosing = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+				// Branch with one ball less.
+				Self::enclosing_balls_with_bounds(balls, bounds)
+			});
+			if let Some(enclosing) = enclosing.filter(|enclosing| enclosing.contains_ball(&ball)) {
+				// Move ball to back.
+				balls.push_back(ball);
+				debug_assert_eq!(balls.len(), len, "deque length not conserved");
+				Some(enclosing)
+			} else {
+				// Move ball to bounds.
+				bounds.push(ball);
+				let enclosing = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+					// Branch with one ball less and one bound more.
+					Self::enclosing_balls_with_bounds(balls, bounds)
+				});
+				// Move ball to front.
+				balls.push_front(bounds.pop().unwrap());
+				debug_assert_eq!(balls.len(), len, "deque length not conserved");
+				enclosing
+			}
+		} else {
+			// Minimum ball with bounds tangent to surface.
+			Self::with_tangent_bounds(bounds)
+		}
+	}
+	/// Returns minimum ball with all `bounds` tangent to the surface from inside or `None` if it
+	/// does not exist.
+	///
+	/// The center `c = c₀ + x` is in the affine hull of the centers `cᵢ = c₀ + vᵢ` of the bounds
+	/// of radii `rᵢ`. Subtracting the tangency conditions `|x - vᵢ|² = (R - rᵢ)²` from the one of
+	/// the first bound yields a linear system in `x` and radius `R` which is solved for `x` in
+	/// terms of `R`. Substituting it into `|x|² = (R - r₀)²` yields a quadratic equation of which
+	/// the smallest root with `R ≥ rᵢ` is taken.
+	///
+	/// Helper for [`Self::enclosing_balls()`].
+	#[cfg(feature = "std")]
+	fn with_tangent_bounds(bounds: &[Self]) -> Option<Self>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let length = bounds.len().checked_sub(1).filter(|&len| len <= D::USIZE)?;
+		let (first, bounds) = bounds.split_first()?;
+		let radius = first.radius();
+		let vectors = OMatrix::<T, D, D>::from_fn(|row, column| {
+			if column < length {
+				bounds[column].center[row].clone() - first.center[row].clone()
+			} else {
+				T::zero()
+			}
+		});
+		let vectors = vectors.view((0, 0), (D::USIZE, length));
+		let matrix = OMatrix::<T, D, D>::from_fn(|row, column| {
+			if row < length && column < length {
+				vectors.column(row).dot(&vectors.column(column))
+			} else {
+				T::zero()
+			}
+		});
+		let matrix = matrix.view((0, 0), (length, length));
+		// Right-hand side `offsets + R * slopes` of linear system.
+		let offsets = OVector::<T, D>::from_fn(|row, _column| {
+			if row < length {
+				(vectors.column(row).norm_squared() - bounds[row].radius_squared.clone()
+					+ first.radius_squared.clone())
+					/ (T::one() + T::one())
+			} else {
+				T::zero()
+			}
+		});
+		let slopes = OVector::<T, D>::from_fn(|row, _column| {
+			if row < length {
+				bounds[row].radius() - radius.clone()
+			} else {
+				T::zero()
+			}
+		});
+		let offsets = offsets.view((0, 0), (length, 1));
+		let slopes = slopes.view((0, 0), (length, 1));
+		matrix.try_inverse().and_then(|matrix| {
+			let (offsets, slopes) = (&matrix * offsets, matrix * slopes);
+			let mut offset = OVector::<T, D>::zeros();
+			let mut slope = OVector::<T, D>::zeros();
+			for bound in 0..length {
+				offset += vectors.column(bound) * offsets[bound].clone();
+				slope += vectors.column(bound) * slopes[bound].clone();
+			}
+			// Quadratic equation `a R² + 2 b R + c = 0`.
+			let a = slope.norm_squared() - T::one();
+			let b = offset.dot(&slope) + radius.clone();
+			let c = offset.norm_squared() - first.radius_squared.clone();
+			let epsilon = T::default_epsilon().sqrt();
+			let roots = if a == T::zero() {
+				let root = -c / (b.clone() + b);
+				[root.clone(), root]
+			} else {
+				let discriminant = b.clone() * b.clone() - a.clone() * c;
+				if discriminant < -(b.clone() * b.clone() * epsilon.clone()) {
+					return None;
+				}
+				let root = discriminant.max(T::zero()).sqrt();
+				[(-b.clone() - root.clone()) / a.clone(), (root - b) / a]
+			};
+			let max_radius = bounds.iter().map(Self::radius).fold(radius, T::max);
+			let minimum = T::one() - epsilon;
+			let root = roots
+				.into_iter()
+				.filter(|root| root.is_finite() && *root >= max_radius.clone() * minimum.clone())
+				.reduce(T::min)?;
+			let root = root.max(max_radius);
+			Some(Self {
+				center: &first.center + offset + slope * root.clone(),
+				radius_squared: root.clone() * root,
+			})
+		})
+	}
+	/// Returns circumscribed 2-ball of 3 `bounds` or 3-ball of 4 `bounds` or an error if they are
+	/// degenerate.
+	///
+	/// Reconsiders bounds deemed affinely dependent by inverting a possibly ill-conditioned matrix
+	/// with the exact orientation predicates of the [`robust`] crate. The determinant of the
+	/// orientation predicate is evaluated with adaptive precision and divides the cofactor
+	/// expansion of the center relative to the first bound. The bounds are still deemed affinely
+	/// dependent if the determinant is within the epsilon of `T` relative to its Hadamard bound as
+	/// the bounds are only resolved up to it. Computes in `f64` regardless of `T` without fused
+	/// multiply-add as it requires `std`.
+	///
+	/// Helper for [`Self::with_bounds_result()`].
+	#[cfg(feature = "robust")]
+	#[allow(clippy::suboptimal_flops)]
+	fn with_robust_bounds(bounds: &[OPoint<T, D>]) -> Result<Self, WithBoundsError> {
+		let sub = |[ax, ay, az]: [f64; 3], [bx, by, bz]: [f64; 3]| [ax - bx, ay - by, az - bz];
+		let dot = |[ax, ay, az]: [f64; 3], [bx, by, bz]: [f64; 3]| ax * bx + ay * by + az * bz;
+		let cross = |[ax, ay, az]: [f64; 3], [bx, by, bz]: [f64; 3]| {
+			[ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx]
+		};
+		let mut points = [[0.0; 3]; 4];
+		for (point, bound) in points.iter_mut().zip(bounds) {
+			for (coordinate, bound) in point.iter_mut().zip(bound.iter()) {
+				*coordinate = bound.to_subset().ok_or(WithBoundsError::NonFinite)?;
+			}
+		}
+		// Bounds relative to first bound.
+		let [a, b, c, d] = points;
+		let (b, c, d) = (sub(b, a), sub(c, a), sub(d, a));
+		let (b_norm_squared, c_norm_squared) = (dot(b, b), dot(c, c));
+		// Determinant of relative bounds and center relative to first bound scaled by it twice.
+		let (determinant, center, d_norm_squared) = if D::USIZE == 2 {
+			let [a, b_, c_, _d] = points.map(|[x, y, _z]| Coord { x, y });
+			let center = [
+				c[1] * b_norm_squared - b[1] * c_norm_squared,
+				b[0] * c_norm_squared - c[0] * b_norm_squared,
+				0.0,
+			];
+			(orient2d(a, b_, c_), center, 1.0)
+		} else {
+			let [a, b_, c_, d_] = points.map(|[x, y, z]| Coord3D { x, y, z });
+			let d_norm_squared = dot(d, d);
+			let (cd, db, bc) = (cross(c, d), cross(d, b), cross(b, c));
+			let center = [0, 1, 2].map(|axis| {
+				b_norm_squared * cd[axis] + c_norm_squared * db[axis] + d_norm_squared * bc[axis]
+			});
+			// Negated as the predicate expands the determinant relative to the last bound.
+			(-orient3d(a, b_, c_, d_), center, d_norm_squared)
+		};
+		// Determinant squared relative to its Hadamard bound squared, divided stepwise.
+		let ratio = determinant / b_norm_squared * determinant / c_norm_squared / d_norm_squared;
+		let epsilon = T::default_epsilon();
+		if determinant == 0.0 || T::from_subset(&ratio) <= epsilon.clone() * epsilon {
+			return Err(WithBoundsError::AffinelyDependent);
+		}
+		let center = center.map(|coordinate| coordinate / (2.0 * determinant));
+		let radius_squared = dot(center, center);
+		if !radius_squared.is_finite() {
+			return Err(WithBoundsError::NonFinite);
+		}
+		Ok(Self {
+			center: OPoint::from(OVector::<T, D>::from_fn(|row, _column| {
+				T::from_subset(&(a[row] + center[row]))
+			})),
+			radius_squared: T::from_subset(&radius_squared),
+		})
+	}
+	/// Returns approximate ball enclosing `points` by Ritter's algorithm.
+	///
+	/// Starts with the ball having the approximately farthest-apart pair of `points` as diameter
+	/// and grows it by each point outside in a single pass.
+	fn ritter(points: &mut impl Deque<OPoint<T, D>>) -> Self {
+		assert!(!points.is_empty(), "empty point set");
+		let farthest_from = |points: &mut _, from: &OPoint<T, D>| {
+			let mut farthest = (from.clone(), T::zero());
+			deque::for_each(points, |point| {
+				let distance = (point - from).norm_squared();
+				if distance > farthest.1 {
+					farthest = (point.clone(), distance);
+				}
+			});
+			farthest.0
+		};
+		let first = points.pop_front().unwrap();
+		points.push_front(first.clone());
+		let a = farthest_from(points, &first);
+		let b = farthest_from(points, &a);
+		let mut ball = Self {
+			center: &a + (&b - &a).scale(T::one() / T::from_usize(2).unwrap()),
+			radius_squared: (b - a).norm_squared() / T::from_usize(4).unwrap(),
+		};
+		deque::for_each(points, |point| ball.expand_to_contain(point));
+		ball
+	}
+}
+
+impl<T: RealField, const D: usize> Ball<T, Const<D>> {
+	/// Whether ball contains grid block of `counts` cells of `cell_size` starting at `origin`.
+	///
+	/// Tests the axis-aligned box of the block, see [`Self::contains_box()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `counts` are not representable in `T`.
+	#[must_use]
+	pub fn contains_grid_block(
+		&self,
+		origin: &OPoint<T, Const<D>>,
+		cell_size: T,
+		counts: &[usize; D],
+	) -> bool {
+		let size = OVector::<T, Const<D>>::from_fn(|row, _column| {
+			T::from_usize(counts[row]).expect("unrepresentable count")
+		}) * cell_size;
+		let max = origin + size;
+		self.contains_box(origin, &max)
+	}
+	/// Returns integer grid coordinates spanned by the axis-aligned bounding box of the ball.
+	///
+	/// Returns `(min, max)` where `min` is the floor and `max` is the ceiling of the lower and upper
+	/// corner of the box divided by `cell_size`. The box is conservatively covered by the cells
+	/// with indices from `min` inclusive to `max` exclusive where the cell with index `i` ranges
+	/// from `i * cell_size` to `(i + 1) * cell_size`. At cell boundaries, a box merely touching
+	/// the next cell does not span it. Grid coordinates out of range of [`i64`] saturate.
+	///
+	/// # Panics
+	///
+	/// Panics if `cell_size` is not positive or if the ball is not representable in [`f64`].
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn voxel_bounds(&self, cell_size: T) -> ([i64; D], [i64; D]) {
+		assert!(cell_size > T::zero(), "non-positive cell size");
+		let (lower, upper) = self.aabb();
+		let index = move |coordinate: T, round: fn(T) -> T| {
+			let index = round(coordinate / cell_size.clone());
+			try_convert::<T, f64>(index).expect("unrepresentable ball") as i64
+		};
+		let (mut min, mut max) = ([0; D], [0; D]);
+		for axis in 0..D {
+			min[axis] = index(lower[axis].clone(), T::floor);
+			max[axis] = index(upper[axis].clone(), T::ceil);
+		}
+		(min, max)
+	}
+	/// Returns ball transformed by isometry `iso`.
+	///
+	/// Transforms the center whereas the radius is preserved by isometries.
+	#[must_use]
+	pub fn transformed_by<R: AbstractRotation<T, D>>(&self, iso: &Isometry<T, R, D>) -> Self {
+		Self {
+			center: iso.transform_point(&self.center),
+			radius_squared: self.radius_squared.clone(),
+		}
+	}
+	/// Returns ball transformed by `rotation` followed by `translation`.
+	///
+	/// Same as [`Self::transformed_by()`] with separate `rotation` and `translation`. As a rigid
+	/// transform preserves distances, the minimum ball of the transformed points is the minimum
+	/// ball of the original points with its center transformed, so the points do not need to be
+	/// transformed and enclosed again.
+	#[must_use]
+	pub fn transformed_rigid(
+		&self,
+		rotation: &Rotation<T, D>,
+		translation: &SVector<T, D>,
+	) -> Self {
+		Self {
+			center: rotation * &self.center + translation,
+			radius_squared: self.radius_squared.clone(),
+		}
+	}
+	/// Returns ball enclosing the ball transformed by `matrix` followed by `translation`.
+	///
+	/// The center is transformed whereas the radius is scaled by the largest singular value of
+	/// `matrix`, i.e., the maximum stretch of any direction. The returned ball conservatively
+	/// encloses the transformed ball which is an ellipsoid unless `matrix` is a uniform scaling
+	/// combined with a rotation or reflection. It is not necessarily the minimum ball of the
+	/// transformed points.
+	#[must_use]
+	pub fn transformed_affine(&self, matrix: &SMatrix<T, D, D>, translation: &SVector<T, D>) -> Self
+	where
+		Const<D>: DimMin<Const<D>, Output = Const<D>> + DimSub<U1>,
+		DefaultAllocator: Allocator<T, DimDiff<Const<D>, U1>>,
+	{
+		let scale = matrix.clone().singular_values().max();
+		Self {
+			center: matrix * &self.center + translation,
+			radius_squared: self.radius_squared.clone() * scale.clone() * scale,
+		}
+	}
+}
+
+impl<T: RealField> Ball<T, U2> {
+	/// Returns circumcircle of triangle given by its three `vertices` or `None` if degenerate.
+	///
+	/// Same as [`Enclosing::with_bounds()`] but takes exactly three bounds, ruling out too few or
+	/// too many at compile time. It is provided per dimension as the length of the array cannot
+	/// depend on a generic dimension. Returns `None` if the `vertices` are collinear.
+	#[must_use]
+	#[inline]
+	pub fn circumscribe_simplex(vertices: &[Point2<T>; 3]) -> Option<Self> {
+		Self::with_bounds(vertices)
+	}
+	/// Returns center, width, and height of largest rectangle of `aspect_ratio` inscribed in ball.
+	///
+	/// The `aspect_ratio` is the width divided by the height. The rectangle is centered at the
+	/// center of the ball with its diagonal being the diameter. As the ball is rotationally
+	/// symmetric, any rotation of the rectangle about the center is inscribed as well.
+	#[must_use]
+	pub fn largest_inscribed_rect(&self, aspect_ratio: T) -> (Point2<T>, T, T) {
+		let diameter = self.radius_squared.clone().sqrt() * (T::one() + T::one());
+		let height = diameter / (T::one() + aspect_ratio.clone() * aspect_ratio.clone()).sqrt();
+		let width = height.clone() * aspect_ratio;
+		(self.center.clone(), width, height)
+	}
+}
+
+impl<T: RealField> Ball<T, U3> {
+	/// Returns circumsphere of tetrahedron given by its four `vertices` or `None` if degenerate.
+	///
+	/// Same as [`Enclosing::with_bounds()`] but takes exactly four bounds, ruling out too few or
+	/// too many at compile time. It is provided per dimension as the length of the array cannot
+	/// depend on a generic dimension. Returns `None` if the `vertices` are coplanar.
+	#[must_use]
+	#[inline]
+	pub fn circumscribe_simplex(vertices: &[Point3<T>; 4]) -> Option<Self> {
+		Self::with_bounds(vertices)
+	}
+	/// Whether ball contains view frustum given by its eight `corners`.
+	///
+	/// As the frustum is convex, it is contained iff all its corners are contained.
+	#[must_use]
+	pub fn contains_frustum(&self, corners: &[Point3<T>; 8]) -> bool {
+		corners.iter().all(|corner| self.contains(corner))
+	}
+	/// Returns signed distance field of ball sampled at cell centers of a grid.
+	///
+	/// The grid has `counts` cells of `cell_size` per axis starting at `origin`. The distances
+	/// follow the convention of signed distance fields, that is negative inside and positive
+	/// outside, see [`Self::signed_distance()`]. They are in row-major order, that is the
+	/// index of cell `[x, y, z]` is `(x * counts[1] + y) * counts[2] + z`.
+	///
+	/// # Panics
+	///
+	/// Panics if `counts` are not representable in `T`.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn sample_sdf_grid(&self, origin: &Point3<T>, cell_size: T, counts: [usize; 3]) -> Vec<T> {
+		let half = T::one() / (T::one() + T::one());
+		let coordinate = move |axis: usize, index: usize| {
+			let index = T::from_usize(index).expect("unrepresentable count") + half.clone();
+			origin[axis].clone() + index * cell_size.clone()
+		};
+		let mut distances = Vec::with_capacity(counts.iter().product());
+		for x in 0..counts[0] {
+			for y in 0..counts[1] {
+				for z in 0..counts[2] {
+					let center = Point3::new(coordinate(0, x), coordinate(1, y), coordinate(2, z));
+					distances.push(self.signed_distance(&center));
+				}
+			}
+		}
+		distances
+	}
+}
+
+impl<T: RealField + Copy, D: DimName> Copy for Ball<T, D>
+where
+	OPoint<T, D>: Copy,
+	DefaultAllocator: Allocator<T, D>,
+{
+}
+
+impl<T: RealField, D: DimName> PartialEq for Ball<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	fn eq(&self, other: &Self) -> bool {
+		assert!(
+			self.radius_squared.is_finite() && other.radius_squared.is_finite(),
+			"infinite ball"
+		);
+		self.center == other.center && self.radius_squared == other.radius_squared
+	}
+}
+
+#[cfg(feature = "approx")]
+impl<T: RealField, D: DimName> AbsDiffEq for Ball<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	type Epsilon = T;
+
+	#[inline]
+	fn default_epsilon() -> Self::Epsilon {
+		T::default_epsilon()
+	}
+	#[inline]
+	fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+		self.center.abs_diff_eq(&other.center, epsilon.clone())
+			&& self
+				.radius_squared
+				.abs_diff_eq(&other.radius_squared, epsilon)
+	}
+}
+
+#[cfg(feature = "approx")]
+impl<T: RealField, D: DimName> RelativeEq for Ball<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	#[inline]
+	fn default_max_relative() -> Self::Epsilon {
+		T::default_max_relative()
+	}
+	#[inline]
+	fn relative_eq(
+		&self,
+		other: &Self,
+		epsilon: Self::Epsilon,
+		max_relative: Self::Epsilon,
+	) -> bool {
+		self.center
+			.relative_eq(&other.center, epsilon.clone(), max_relative.clone())
+			&& self
+				.radius_squared
+				.relative_eq(&other.radius_squared, epsilon, max_relative)
+	}
+}
+
+impl<T: RealField, D: DimName> Enclosing<T, D> for Ball<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	#[inline]
+	fn contains(&self, point: &OPoint<T, D>) -> bool {
+		self.locate(point) != Location::Outside
+	}
+	/// Returns ball circumscribed by two approximately farthest-apart `points` grown to enclose
+	/// the remaining ones by [Ritter's algorithm].
+	///
+	/// This fallback trades minimality for never failing.
+	///
+	/// [Ritter's algorithm]: https://doi.org/10.1016/B978-0-08-050753-8.50063-2
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty.
+	fn enclosing_points_fallback(points: &mut impl Deque<OPoint<T, D>>) -> Option<Self> {
+		Some(Self::ritter(points))
+	}
+	fn with_bounds(bounds: &[OPoint<T, D>]) -> Option<Self>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		Self::with_bounds_result(bounds).ok()
+	}
 }
@@ -0,0 +1,144 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::Ball;
+use nalgebra::{base::allocator::Allocator, DefaultAllocator, DimName, OPoint, RealField};
+
+/// Ball with its radius cached alongside its radius squared.
+///
+/// Radius-dependent methods of [`Ball`] compute the radius as square root of its radius squared
+/// on each invocation. This wrapper computes it once and keeps both in sync as its fields are
+/// only accessible through setters. It implements [`Self::signed_distance()`],
+/// [`Self::distance_to_surface()`], [`Self::project_to_surface()`], [`Self::aabb()`],
+/// [`Self::grow_by()`], [`Self::shrink_by()`], and [`Self::lerp()`] with the cached radius, whereas
+/// the other methods are accessible via [`Self::ball()`] and compute the radius as usual.
+#[derive(Debug, Clone)]
+pub struct BallWithRadius<T: RealField, D: DimName>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	ball: Ball<T, D>,
+	radius: T,
+}
+
+impl<T: RealField, D: DimName> BallWithRadius<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	/// Wraps `ball` caching its radius.
+	#[must_use]
+	pub fn new(ball: Ball<T, D>) -> Self {
+		let radius = ball.radius();
+		Self { ball, radius }
+	}
+	/// Wrapped ball.
+	#[must_use]
+	#[inline]
+	pub const fn ball(&self) -> &Ball<T, D> {
+		&self.ball
+	}
+	/// Unwraps ball.
+	#[must_use]
+	#[inline]
+	pub fn into_ball(self) -> Ball<T, D> {
+		self.ball
+	}
+	/// Ball's cached radius.
+	#[must_use]
+	#[inline]
+	pub fn radius(&self) -> T {
+		self.radius.clone()
+	}
+	/// Sets ball's center.
+	#[inline]
+	pub fn set_center(&mut self, center: OPoint<T, D>) {
+		self.ball.center = center;
+	}
+	/// Sets ball's radius and radius squared.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `radius` is negative or not finite.
+	pub fn set_radius(&mut self, radius: T) {
+		self.ball = Ball::new(self.ball.center.clone(), radius.clone());
+		self.radius = radius;
+	}
+	/// Returns signed distance of `point` to the surface, see [`Ball::signed_distance()`].
+	#[must_use]
+	pub fn signed_distance(&self, point: &OPoint<T, D>) -> T {
+		self.ball.signed_distance_with_radius(point, &self.radius)
+	}
+	/// Returns distance of `point` to the surface, see [`Ball::distance_to_surface()`].
+	#[must_use]
+	pub fn distance_to_surface(&self, point: &OPoint<T, D>) -> T {
+		self.signed_distance(point).abs()
+	}
+	/// Returns point on the surface closest to `point`, see [`Ball::project_to_surface()`].
+	#[must_use]
+	#[inline]
+	pub fn project_to_surface(&self, point: &OPoint<T, D>) -> OPoint<T, D> {
+		self.ball
+			.project_to_surface_with_radius(point, &self.radius)
+	}
+	/// Returns minimum and maximum corner of axis-aligned bounding box, see [`Ball::aabb()`].
+	#[must_use]
+	#[inline]
+	pub fn aabb(&self) -> (OPoint<T, D>, OPoint<T, D>) {
+		self.ball.aabb_with_radius(&self.radius)
+	}
+	/// Returns ball grown by absolute `margin` about its center, see [`Ball::grow_by()`].
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `margin` is not finite.
+	#[must_use]
+	pub fn grow_by(&self, margin: T) -> Self {
+		let (ball, radius) = self.ball.grow_by_with_radius(margin, &self.radius);
+		Self { ball, radius }
+	}
+	/// Returns ball shrunk by absolute `margin` about its center, see [`Ball::shrink_by()`].
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `margin` is not finite.
+	#[must_use]
+	pub fn shrink_by(&self, margin: T) -> Self {
+		self.grow_by(-margin)
+	}
+	/// Returns ball linearly interpolated between ball and `other` ball by `t`, see
+	/// [`Ball::lerp()`].
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if `t` is not finite.
+	#[must_use]
+	pub fn lerp(&self, other: &Self, t: T) -> Self {
+		let (ball, radius) = self
+			.ball
+			.lerp_with_radii(&other.ball, t, &self.radius, &other.radius);
+		Self { ball, radius }
+	}
+}
+
+impl<T: RealField, D: DimName> From<Ball<T, D>> for BallWithRadius<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	#[inline]
+	fn from(ball: Ball<T, D>) -> Self {
+		Self::new(ball)
+	}
+}
+
+impl<T: RealField, D: DimName> From<BallWithRadius<T, D>> for Ball<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	#[inline]
+	fn from(ball: BallWithRadius<T, D>) -> Self {
+		ball.into_ball()
+	}
+}
@@ -4,10 +4,20 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
+#[cfg(feature = "heapless")]
+use heapless::Deque as HeaplessDeque;
 #[cfg(feature = "std")]
 use std::collections::{LinkedList, VecDeque};
 
 /// Minimum double-ended queue interface.
+///
+/// Implementations must conserve elements, that is pushing an element must increase the length by
+/// one and popping an element must decrease it by one unless empty. Algorithms like
+/// [`Enclosing::enclosing_points()`](crate::Enclosing::enclosing_points) pop and push back each
+/// element exactly once per recursion step and would silently compute a wrong result otherwise.
+/// This is checked by debug assertions.
 pub trait Deque<T> {
 	/// Returns the number of elements in the deque.
 	#[must_use]
@@ -81,3 +91,78 @@ impl<T> Deque<T> for LinkedList<T> {
 		Self::push_back(self, value);
 	}
 }
+
+/// Stack-allocated deque of capacity `N` for `no_std` environments.
+///
+/// Front operations shift all elements. Pushing an element panics if the deque is at capacity.
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> Deque<T> for ArrayVec<T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		Self::len(self)
+	}
+
+	#[inline]
+	fn pop_front(&mut self) -> Option<T> {
+		Self::pop_at(self, 0)
+	}
+	#[inline]
+	fn pop_back(&mut self) -> Option<T> {
+		Self::pop(self)
+	}
+
+	#[inline]
+	fn push_front(&mut self, value: T) {
+		Self::insert(self, 0, value);
+	}
+	#[inline]
+	fn push_back(&mut self, value: T) {
+		Self::push(self, value);
+	}
+}
+
+/// Stack-allocated ring buffer of capacity `N` for environments without allocator.
+///
+/// Pushing an element panics if the deque is at capacity, consistent with `ArrayVec`, as
+/// silently dropping it would violate the conservation of elements.
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> Deque<T> for HeaplessDeque<T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		Self::len(self)
+	}
+
+	#[inline]
+	fn pop_front(&mut self) -> Option<T> {
+		Self::pop_front(self)
+	}
+	#[inline]
+	fn pop_back(&mut self) -> Option<T> {
+		Self::pop_back(self)
+	}
+
+	#[inline]
+	fn push_front(&mut self, value: T) {
+		assert!(
+			Self::push_front(self, value).is_ok(),
+			"insufficient capacity"
+		);
+	}
+	#[inline]
+	fn push_back(&mut self, value: T) {
+		assert!(
+			Self::push_back(self, value).is_ok(),
+			"insufficient capacity"
+		);
+	}
+}
+
+/// Visits all elements of `deque` from front to back by rotating it once.
+pub fn for_each<T>(deque: &mut impl Deque<T>, mut f: impl FnMut(&T)) {
+	for _ in 0..deque.len() {
+		if let Some(element) = deque.pop_front() {
+			f(&element);
+			deque.push_back(element);
+		}
+	}
+}
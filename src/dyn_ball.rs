@@ -0,0 +1,179 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Deque, Location};
+use nalgebra::{DMatrix, DVector, RealField};
+use stacker::maybe_grow;
+
+/// Ball over real field `T` of dimension determined at runtime with center and radius squared.
+///
+/// Counterpart of [`Ball`](crate::Ball) for points of a dimension unknown at compile time, e.g.,
+/// read from a file. Points are stored as [`DVector`] on the heap, so the recursion steps of
+/// [`Self::enclosing_points()`] are of constant stack size independent of the dimension.
+#[derive(Debug, Clone)]
+pub struct DynBall<T: RealField> {
+	/// Ball's center.
+	pub center: DVector<T>,
+	/// Ball's radius squared.
+	pub radius_squared: T,
+}
+
+impl<T: RealField> DynBall<T> {
+	/// Guaranteed stack size per recursion step.
+	const RED_ZONE: usize = 32 * 1_024;
+	/// New stack space to allocate if within [`Self::RED_ZONE`].
+	const STACK_SIZE: usize = Self::RED_ZONE * 1_024;
+
+	/// Ball's dimension.
+	#[must_use]
+	#[inline]
+	pub fn dim(&self) -> usize {
+		self.center.len()
+	}
+	/// Ball's radius.
+	#[must_use]
+	#[inline]
+	pub fn radius(&self) -> T {
+		self.radius_squared.clone().sqrt()
+	}
+	/// Whether ball contains `point`.
+	///
+	/// Same relative tolerance as [`Enclosing::contains()`](crate::Enclosing::contains), see
+	/// [`Self::locate()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is infinite or of another dimension.
+	#[must_use]
+	pub fn contains(&self, point: &DVector<T>) -> bool {
+		self.locate(point) != Location::Outside
+	}
+	/// Locates `point` strictly inside, on the surface, or outside of ball.
+	///
+	/// Same as [`Ball::locate()`](crate::Ball::locate).
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is infinite or of another dimension.
+	#[must_use]
+	pub fn locate(&self, point: &DVector<T>) -> Location {
+		let norm_squared = (point - &self.center).norm_squared();
+		assert!(norm_squared.is_finite(), "infinite point");
+		let difference = self.radius_squared.clone() - norm_squared.clone();
+		let tolerance = norm_squared * T::default_epsilon().sqrt();
+		if difference < -tolerance.clone() {
+			Location::Outside
+		} else if difference > tolerance {
+			Location::Inside
+		} else {
+			Location::Surface
+		}
+	}
+	/// Returns circumscribed ball with all `bounds` on surface or `None` if it does not exist.
+	///
+	/// Same as [`Enclosing::with_bounds()`](crate::Enclosing::with_bounds) with the dimension
+	/// given by the first bound. Returns `None` if there are no bounds or more than one bound
+	/// more than dimensions.
+	///
+	/// # Panics
+	///
+	/// Panics if `bounds` differ in dimension.
+	#[must_use]
+	pub fn with_bounds(bounds: &[DVector<T>]) -> Option<Self> {
+		let (origin, bounds) = bounds.split_first()?;
+		let dim = origin.len();
+		assert!(
+			bounds.iter().all(|bound| bound.len() == dim),
+			"mismatching dimension"
+		);
+		let length = bounds.len();
+		if length > dim {
+			return None;
+		}
+		let points = DMatrix::from_fn(dim, length, |row, column| {
+			bounds[column][row].clone() - origin[row].clone()
+		});
+		let matrix = points.tr_mul(&points) * (T::one() + T::one());
+		let vector = DVector::from_fn(length, |row, _column| points.column(row).norm_squared());
+		matrix.try_inverse().and_then(|matrix| {
+			let center = &points * (matrix * vector);
+			let radius_squared = center.norm_squared();
+			let center = origin + center;
+			radius_squared.is_finite().then(|| Self {
+				center,
+				radius_squared,
+			})
+		})
+	}
+	/// Returns minimum ball enclosing `points`.
+	///
+	/// Implements [Welzl's recursive algorithm] with move-to-front heuristic like
+	/// [`Enclosing::enclosing_points()`](crate::Enclosing::enclosing_points) but without its
+	/// pre-pass for collinear points. The dimension is given by the first point.
+	///
+	/// [Welzl's recursive algorithm]: https://api.semanticscholar.org/CorpusID:17569809
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty, differ in dimension, or on numerical instability.
+	#[must_use]
+	pub fn enclosing_points(points: &mut impl Deque<DVector<T>>) -> Self {
+		let origin = points.pop_front().expect("empty point set");
+		let dim = origin.len();
+		points.push_front(origin);
+		let mut bounds = Vec::with_capacity(dim + 1);
+		(0..=dim)
+			.find_map(|_| {
+				maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+					Self::enclosing_points_with_bounds(points, &mut bounds, dim)
+				})
+			})
+			.expect("numerical instability")
+	}
+	/// Returns minimum ball enclosing `points` with `bounds` of at most `dim + 1` points.
+	///
+	/// Recursive helper for [`Self::enclosing_points()`].
+	fn enclosing_points_with_bounds(
+		points: &mut impl Deque<DVector<T>>,
+		bounds: &mut Vec<DVector<T>>,
+		dim: usize,
+	) -> Option<Self> {
+		// Length of deque to be conserved by each recursion step.
+		let len = points.len();
+		// Take point from back unless bounds are full as it would get lost otherwise.
+		let point = if bounds.len() > dim {
+			None
+		} else {
+			points.pop_back()
+		};
+		if let Some(point) = point {
+			let ball = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+				// Branch with one point less.
+				Self::enclosing_points_with_bounds(points, bounds, dim)
+			});
+			if let Some(ball) = ball.filter(|ball| ball.contains(&point)) {
+				// Move point to back.
+				points.push_back(point);
+				debug_assert_eq!(points.len(), len, "deque length not conserved");
+				Some(ball)
+			} else {
+				// Move point to bounds.
+				bounds.push(point);
+				let ball = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+					// Branch with one point less and one bound more.
+					Self::enclosing_points_with_bounds(points, bounds, dim)
+				});
+				// Move point to front.
+				points.push_front(bounds.pop().unwrap());
+				debug_assert_eq!(points.len(), len, "deque length not conserved");
+				ball
+			}
+		} else {
+			// Circumscribed ball with bounds.
+			Self::with_bounds(bounds)
+		}
+	}
+}
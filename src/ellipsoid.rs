@@ -0,0 +1,162 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nalgebra::{base::allocator::Allocator, DefaultAllocator, DimName, OMatrix, OPoint, RealField};
+#[cfg(feature = "std")]
+use nalgebra::{DMatrix, DVector};
+
+/// Ellipsoid over real field `T` of dimension `D` with center and shape matrix.
+///
+/// The ellipsoid is the set of points `x` with `(x - center)ᵀ shape (x - center) <= 1` where the
+/// shape matrix is symmetric positive-definite. Its eigenvectors are the directions of the
+/// semi-axes and its eigenvalues are the inverse squared lengths of the semi-axes.
+#[derive(Debug, Clone)]
+pub struct Ellipsoid<T: RealField, D: DimName>
+where
+	DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+	/// Ellipsoid's center.
+	pub center: OPoint<T, D>,
+	/// Ellipsoid's symmetric positive-definite shape matrix.
+	pub shape: OMatrix<T, D, D>,
+}
+
+impl<T: RealField, D: DimName> Ellipsoid<T, D>
+where
+	DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+	/// Returns quadratic form `(point - center)ᵀ shape (point - center)` of `point`.
+	///
+	/// This is less than one inside, one on the surface, and greater than one outside.
+	#[must_use]
+	pub fn quadratic_form(&self, point: &OPoint<T, D>) -> T {
+		let vector = point - &self.center;
+		(&self.shape * &vector).dot(&vector)
+	}
+	/// Whether ellipsoid contains `point`.
+	///
+	/// Tests `quadratic_form * (1 - epsilon) <= 1` with the same relative `epsilon` of
+	/// `T::default_epsilon().sqrt()` as [`Enclosing::contains()`](crate::Enclosing::contains).
+	///
+	/// # Panics
+	///
+	/// Panics if `point` is infinite.
+	#[must_use]
+	pub fn contains(&self, point: &OPoint<T, D>) -> bool {
+		let quadratic_form = self.quadratic_form(point);
+		assert!(quadratic_form.is_finite(), "infinite point");
+		quadratic_form * (T::one() - T::default_epsilon().sqrt()) <= T::one()
+	}
+	/// Returns approximate minimum-volume ellipsoid enclosing `points`.
+	///
+	/// Implements [Khachiyan's first-order algorithm] of the Löwner–John ellipsoid with the
+	/// [away steps] of Todd and Yıldırım for linear convergence. It iteratively shifts the weights
+	/// of `points` toward the one farthest from or away from the one nearest to the current
+	/// ellipsoid as measured by the quadratic form of their lifted covariance until it deviates
+	/// from `D + 1` by at most `(D + 1) * epsilon`. Finally, the ellipsoid is enlarged to enclose
+	/// `points` by dividing its shape by the maximum quadratic form of `points` unless it is less
+	/// than one, so the rescaling never shrinks the ellipsoid. Up to rounding errors, the farthest
+	/// point is on its surface if the rescaling enlarged it.
+	///
+	/// [Khachiyan's first-order algorithm]: https://doi.org/10.1287/moor.21.2.307
+	/// [away steps]: https://doi.org/10.1016/j.dam.2007.02.013
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or does not span `D` dimensions, if `epsilon` is not positive,
+	/// or if the number of points is not representable in `T`.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn enclosing_points(points: &[OPoint<T, D>], epsilon: T) -> Self {
+		assert!(!points.is_empty(), "empty point set");
+		assert!(epsilon > T::zero(), "non-positive epsilon");
+		let (count, dim) = (points.len(), D::USIZE);
+		let lifted_dim = T::from_usize(dim + 1).unwrap();
+		let threshold = lifted_dim.clone() * epsilon;
+		// Points lifted to homogeneous coordinates as columns.
+		let lifted = DMatrix::from_fn(dim + 1, count, |row, column| {
+			if row < dim {
+				points[column][row].clone()
+			} else {
+				T::one()
+			}
+		});
+		let mut weights = DVector::from_element(count, T::one() / T::from_usize(count).unwrap());
+		loop {
+			// Weighted covariance of lifted points.
+			let mut scaled = lifted.clone();
+			for (mut column, weight) in scaled.column_iter_mut().zip(weights.iter()) {
+				column *= weight.clone();
+			}
+			let inverse = (scaled * lifted.transpose())
+				.try_inverse()
+				.expect("degenerate point set");
+			// Quadratic forms of lifted points.
+			let quadratic_forms = (&inverse * &lifted).component_mul(&lifted).row_sum();
+			// Farthest point and nearest point of positive weight.
+			let (farthest, nearest) = quadratic_forms.iter().zip(weights.iter()).enumerate().fold(
+				(0, 0),
+				|(farthest, nearest), (index, (quadratic_form, weight))| {
+					assert!(quadratic_form.is_finite(), "infinite point");
+					let farthest = if *quadratic_form > quadratic_forms[farthest] {
+						index
+					} else {
+						farthest
+					};
+					let nearest = if *weight > T::zero()
+						&& (weights[nearest] <= T::zero()
+							|| *quadratic_form < quadratic_forms[nearest])
+					{
+						index
+					} else {
+						nearest
+					};
+					(farthest, nearest)
+				},
+			);
+			let increase = quadratic_forms[farthest].clone() - lifted_dim.clone();
+			let decrease = lifted_dim.clone() - quadratic_forms[nearest].clone();
+			if increase <= threshold.clone() && decrease <= threshold.clone() {
+				break;
+			}
+			// Either step toward farthest point or away from nearest point.
+			let (index, step) = if increase >= decrease {
+				let quadratic_form = quadratic_forms[farthest].clone();
+				(
+					farthest,
+					increase / (lifted_dim.clone() * (quadratic_form - T::one())),
+				)
+			} else {
+				let quadratic_form = quadratic_forms[nearest].clone();
+				let weight = weights[nearest].clone();
+				let step = -decrease / (lifted_dim.clone() * (quadratic_form - T::one()));
+				(nearest, step.max(-weight.clone() / (T::one() - weight)))
+			};
+			weights *= T::one() - step.clone();
+			weights[index] += step;
+		}
+		// Weighted mean and covariance of points.
+		let center = lifted.rows(0, dim) * &weights;
+		let mut scaled = lifted.rows(0, dim).into_owned();
+		for (mut column, weight) in scaled.column_iter_mut().zip(weights.iter()) {
+			column *= weight.clone();
+		}
+		let covariance = scaled * lifted.rows(0, dim).transpose() - &center * center.transpose();
+		let shape =
+			covariance.try_inverse().expect("degenerate point set") / T::from_usize(dim).unwrap();
+		let mut ellipsoid = Self {
+			center: OPoint::from_slice(center.as_slice()),
+			shape: OMatrix::<T, D, D>::from_fn(|row, column| shape[(row, column)].clone()),
+		};
+		// Enlarge to enclose points but never shrink.
+		let scale = points
+			.iter()
+			.map(|point| ellipsoid.quadratic_form(point))
+			.fold(T::one(), T::max);
+		ellipsoid.shape /= scale;
+		ellipsoid
+	}
+}
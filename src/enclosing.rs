@@ -4,14 +4,24 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::{Deque, OVec};
-use core::mem::size_of;
+use super::{deque, Deque, EnclosingError, OVec};
+#[cfg(feature = "std")]
+use super::{Ball, EnclosingStats};
+#[cfg(feature = "std")]
+use core::mem::replace;
+use core::{borrow::Borrow, mem::size_of};
+#[cfg(feature = "std")]
+use nalgebra::try_convert;
 use nalgebra::{
-	base::allocator::Allocator, DefaultAllocator, DimName, DimNameAdd, DimNameSum, OPoint,
-	RealField, U1,
+	base::allocator::Allocator, DMatrix, DefaultAllocator, DimName, DimNameAdd, DimNameSum, OPoint,
+	OVector, RealField, U1,
 };
+#[cfg(all(feature = "std", feature = "rand"))]
+use rand::{seq::SliceRandom, Rng};
+#[cfg(feature = "std")]
+use stacker::{maybe_grow, remaining_stack};
 #[cfg(feature = "std")]
-use stacker::maybe_grow;
+use std::collections::{HashMap, VecDeque};
 
 #[cfg(not(feature = "std"))]
 #[inline]
@@ -20,16 +30,43 @@ fn maybe_grow<R, F: FnOnce() -> R>(_red_zone: usize, _stack_size: usize, callbac
 }
 
 /// Minimum enclosing ball.
+///
+/// # Implementing
+///
+/// Custom ball-like types implement this trait by supplying [`Self::contains()`] and
+/// [`Self::with_bounds()`] only. All other methods including the recursion of
+/// [`Self::enclosing_points()`] and its stack management are provided. Implementations must
+/// satisfy the following contract:
+///
+///   * [`Self::with_bounds()`] returns the unique ball with all bounds on its surface, i.e., the
+///     one of the affine hull of the bounds, or `None` if the bounds are affinely dependent or
+///     there are more than `D + 1` of them.
+///   * [`Self::contains()`] considers the bounds of a ball returned by [`Self::with_bounds()`] to
+///     be contained, so it must tolerate their floating-point inaccuracies.
+///
+/// Optionally, [`Self::enclosing_points_fallback()`] can be overridden as last resort on numerical
+/// instability, and [`Self::RED_ZONE`] and [`Self::STACK_SIZE`] can be overridden if
+/// [`Self::with_bounds()`] needs considerably more stack space than a ball of `D + 1` points.
+/// Each recursion step of [`Self::enclosing_points()`], [`Self::try_enclosing_points()`], and of
+/// the methods invoking them is [`Self::enclosing_points_with_bounds()`], so it can be overridden
+/// as well, e.g., to instrument it. The methods recording the bounds, i.e.,
+/// [`Self::enclosing_points_scored()`], [`Self::enclosing_points_with_diameter()`], and
+/// [`Self::enclosing_points_with_support()`], the methods moving indices instead of points, i.e.,
+/// [`Self::enclosing_points_indexed()`], [`Self::enclosing_points_flat()`], and
+/// [`Self::enclosing_points_soa()`], `enclosing_points_with_stats()` of the `std` feature, and
+/// [`Self::enclosing_points_iterative()`] run their own recursion steps instead.
 pub trait Enclosing<T: RealField, D: DimName>
 where
 	Self: Clone,
 	DefaultAllocator: Allocator<T, D>,
 {
-	#[doc(hidden)]
 	/// Guaranteed stack size per recursion step.
+	///
+	/// If the remaining stack space falls below, new stack space of [`Self::STACK_SIZE`] is
+	/// allocated on the heap if the `std` feature is enabled. The default accounts for the bounds
+	/// and the temporary matrices of circumscribing a ball of dimension `D`.
 	const RED_ZONE: usize =
 		32 * 1_024 + (8 * D::USIZE + 2 * D::USIZE.pow(2)) * size_of::<OPoint<T, D>>();
-	#[doc(hidden)]
 	/// New stack space to allocate if within [`Self::RED_ZONE`].
 	const STACK_SIZE: usize = Self::RED_ZONE * 1_024;
 
@@ -81,7 +118,11 @@ where
 	/// Implements [Welzl's recursive algorithm] with move-to-front heuristic. No allocations happen
 	/// unless the real field `T` is not [`Copy`] or the stack size enters the dimension-dependant
 	/// red zone in which case temporary stack space will be allocated on the heap if the `std`
-	/// feature is enabled.
+	/// feature is enabled. Collinear points are detected beforehand and enclosed by the ball
+	/// circumscribed by the two extreme ones as Welzl's algorithm is numerically degenerate for
+	/// them. Otherwise, each attempt invokes [`Self::enclosing_points_with_bounds()`] without
+	/// bounds. If all attempts fail due to numerical instability, it falls back to
+	/// [`Self::enclosing_points_fallback()`].
 	///
 	/// [Welzl's recursive algorithm]: https://api.semanticscholar.org/CorpusID:17569809
 	///
@@ -96,7 +137,14 @@ where
 	/// Due to floating-point inaccuracies, the returned ball might not exactly be the minimum for
 	/// degenerate (e.g., co-spherical) `points`. The accuracy is depending on the shape and order
 	/// of `points` with an expected worst-case factor of `T::one() ± T::default_epsilon().sqrt()`
-	/// where `T::one()` is exact.
+	/// where `T::one()` is exact. Points exactly coinciding with a bound are considered enclosed
+	/// and never become another bound, whereas near-duplicate points can be removed beforehand by
+	/// [`Self::deduplicate_near()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability, see
+	/// [`Self::try_enclosing_points()`] for a fallible variant.
 	///
 	/// # Example
 	///
@@ -149,25 +197,707 @@ where
 	#[must_use]
 	#[inline]
 	fn enclosing_points(points: &mut impl Deque<OPoint<T, D>>) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		Self::try_enclosing_points(points).unwrap_or_else(|error| panic!("{error}"))
+	}
+	/// Returns minimum ball enclosing `points` or an error.
+	///
+	/// Same as [`Self::enclosing_points()`] but returns an error instead of panicking.
+	///
+	/// # Errors
+	///
+	/// Returns [`EnclosingError::EmptyPointSet`] if `points` is empty,
+	/// [`EnclosingError::NonFinitePoint`] if `points` contain infinite or NaN coordinates, and
+	/// [`EnclosingError::NumericalInstability`] if all attempts failed and
+	/// [`Self::enclosing_points_fallback()`] returned `None`.
+	#[inline]
+	fn try_enclosing_points(points: &mut impl Deque<OPoint<T, D>>) -> Result<Self, EnclosingError>
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		let origin = points.pop_front().ok_or(EnclosingError::EmptyPointSet)?;
+		points.push_front(origin.clone());
+		// Validate points in the same pass detecting collinear ones.
+		let mut finite = true;
+		let ball = enclosing_collinear_by(origin, |visit| {
+			deque::for_each(points, |point| {
+				finite &= point.iter().all(T::is_finite);
+				visit(point);
+			});
+		});
+		if !finite {
+			return Err(EnclosingError::NonFinitePoint);
+		}
+		ball.map_or_else(|| try_enclosing_points_by(points, &mut Steps), Ok)
+	}
+	/// Returns minimum ball enclosing `points` or `None` if `points` is empty.
+	///
+	/// Same as [`Self::enclosing_points()`] but composes with `?` and [`Iterator::filter_map()`],
+	/// e.g., over clusters which might be empty.
+	///
+	/// # Panics
+	///
+	/// Panics on numerical instability or if `points` contain infinite or NaN coordinates.
+	#[must_use]
+	#[inline]
+	fn enclosing_points_opt(points: &mut impl Deque<OPoint<T, D>>) -> Option<Self>
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		(!points.is_empty()).then(|| Self::enclosing_points(points))
+	}
+	/// Returns ball enclosing `points` as last resort if [`Self::enclosing_points()`] fails.
+	///
+	/// Invoked when all attempts of computing circumscribed balls failed due to numerical
+	/// instability, e.g., when squared distances overflow. Implementations may trade minimality
+	/// for never failing. Returns `None` by default.
+	#[must_use]
+	fn enclosing_points_fallback(_points: &mut impl Deque<OPoint<T, D>>) -> Option<Self> {
+		None
+	}
+	/// Writes minimum ball enclosing `points` into `out`.
+	///
+	/// Same as [`Self::enclosing_points()`] but writes the ball into a pre-existing one instead of
+	/// returning it by value. This is a plain assignment, so whether the move of the center is
+	/// elided for high `D` is up to the optimizer. Its value is the out-parameter signature for
+	/// balls kept in pre-allocated storage, e.g., updating the slots of a static array of balls in
+	/// `no_std` environments in a loop over clusters of points without naming a temporary.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[inline]
+	fn enclosing_points_into(points: &mut impl Deque<OPoint<T, D>>, out: &mut Self)
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		*out = Self::enclosing_points(points);
+	}
+	/// Returns minimum ball enclosing `points` by permuting their `indices` instead of them.
+	///
+	/// Same as [`Self::enclosing_points()`] but the move-to-front heuristic reorders `indices`
+	/// into `points`, so no coordinates are moved except for the at most `D + 1` bounds of each
+	/// recursion step. This pays off for high dimensions `D` where moving points is expensive.
+	/// Afterwards, `indices` are in the order left by the move-to-front heuristic.
+	///
+	/// # Panics
+	///
+	/// Panics if `indices` is empty or out of bounds of `points` or on numerical instability.
+	#[must_use]
+	fn enclosing_points_indexed(points: &[OPoint<T, D>], indices: &mut impl Deque<usize>) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		enclosing_indices_by(&|index| &points[index], indices)
+	}
+	/// Returns minimum ball enclosing points given as flat slice of coordinates.
+	///
+	/// The slice is laid out row-major, i.e., the `i`-th point consists of the `D` coordinates
+	/// `coords[i * D..(i + 1) * D]` as it is common for point clouds passed across FFI boundaries.
+	/// Same as [`Self::enclosing_points_indexed()`] but reads the points of `indices` from `coords`
+	/// on demand instead of copying them into a deque of points beforehand. Afterwards, `indices`
+	/// are in the order left by the move-to-front heuristic.
+	///
+	/// # Panics
+	///
+	/// Panics if the length of `coords` is not a multiple of `D`, if `indices` is empty or out of
+	/// bounds of the points in `coords`, or on numerical instability.
+	#[must_use]
+	fn enclosing_points_flat(coords: &[T], indices: &mut impl Deque<usize>) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert_eq!(coords.len() % D::USIZE, 0, "mismatching dimension");
+		enclosing_indices_by(
+			&|index| {
+				OPoint::from(OVector::<T, D>::from_column_slice(
+					&coords[index * D::USIZE..(index + 1) * D::USIZE],
+				))
+			},
+			indices,
+		)
+	}
+	/// Returns minimum ball enclosing `points` without reordering them.
+	///
+	/// Same as [`Self::enclosing_points()`] but clones `points` into an internal scratch deque
+	/// permuted by the move-to-front heuristic instead of the caller's container, trading an
+	/// allocation for leaving it untouched.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[cfg(feature = "std")]
+	#[must_use]
+	fn enclosing_points_preserving<'a>(points: impl IntoIterator<Item = &'a OPoint<T, D>>) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		Self::enclosing_points(&mut points.into_iter().cloned().collect::<VecDeque<_>>())
+	}
+	/// Returns minimum ball enclosing `points` using the pivoting move-to-front heuristic.
+	///
+	/// Implements [Gärtner's pivoting] variant of [`Enclosing::enclosing_points()`]. Instead of
+	/// processing all points in order, it repeatedly picks the point farthest from the current ball
+	/// as pivot, moves it to the front of `points`, and recomputes the ball of the pivot and the
+	/// prefix of `points` preceding it with the pivot on its surface. Points deep inside the final
+	/// ball are only ever tested against it but never processed by the recursion.
+	///
+	/// This is expected to speed up clustered data as the farthest point of an outer cluster tends
+	/// to be on the surface of the final ball whereas all the other points of the same cluster are
+	/// skipped. The resulting ball matches the one of [`Enclosing::enclosing_points()`] within the
+	/// documented numerical stability.
+	///
+	/// Takes a slice instead of a [`Deque`] as pivots are moved to the front from anywhere within
+	/// `points`, e.g., a `&mut Vec<OPoint<T, D>>` coerces to it. Rounding may exclude a point of
+	/// the prefix from its recomputed ball in which case the prefix would not grow anymore. Then,
+	/// this falls back to [`Self::enclosing_points()`] of all `points` instead of looping forever.
+	///
+	/// Requires access to the ball's center for finding the pivot, so this is only available to
+	/// implementations borrowing a [`Ball`], e.g., wrapping it to instrument it. Implementations
+	/// with their own representation of a ball cannot invoke this method.
+	///
+	/// [Gärtner's pivoting]: https://doi.org/10.1007/3-540-48481-7_29
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[cfg(feature = "std")]
+	#[must_use]
+	fn enclosing_points_pivot(points: &mut [OPoint<T, D>]) -> Self
+	where
+		Self: Borrow<Ball<T, D>>,
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert!(!points.is_empty(), "empty point set");
+		let mut prefix = 1;
+		let mut ball = Self::with_bounds(&points[..prefix]).expect("numerical instability");
+		loop {
+			// Find pivot farthest from center.
+			let pivot = points
+				.iter()
+				.map(|point| (point - &ball.borrow().center).norm_squared())
+				.enumerate()
+				.max_by(|(_, a), (_, b)| a.partial_cmp(b).expect("infinite point"))
+				.map(|(pivot, _distance)| pivot)
+				.unwrap();
+			if ball.contains(&points[pivot]) {
+				return ball;
+			}
+			// Fall back if rounding excluded a point of prefix whose ball would not grow.
+			if pivot < prefix {
+				let mut deque = points.iter().cloned().collect::<VecDeque<_>>();
+				let ball = Self::enclosing_points(&mut deque);
+				for (point, deque_point) in points.iter_mut().zip(deque) {
+					*point = deque_point;
+				}
+				return ball;
+			}
+			// Move pivot to front and grow prefix by it.
+			points[..=pivot].rotate_right(1);
+			prefix += 1;
+			// Recompute ball of prefix with pivot as bound.
+			let mut bounds = OVec::new();
+			bounds.push(points[0].clone());
+			let mut prefix_points = points[1..prefix].iter().cloned().collect::<VecDeque<_>>();
+			ball = (0..bounds.capacity())
+				.find_map(|_| {
+					maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+						Self::enclosing_points_with_bounds(&mut prefix_points, &mut bounds)
+					})
+				})
+				.expect("numerical instability");
+			// Keep order of prefix as permuted by move-to-front heuristic.
+			for (point, prefix_point) in points[1..prefix].iter_mut().zip(prefix_points) {
+				*point = prefix_point;
+			}
+		}
+	}
+	/// Returns minimum ball enclosing `points` randomly permuted by `rng` beforehand.
+	///
+	/// Collects `points` into a slice, shuffles it by the Fisher-Yates algorithm, and moves them
+	/// back before invoking [`Self::enclosing_points()`]. This ensures the expected time
+	/// complexity while being reproducible for a seeded `rng`.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[cfg(all(feature = "std", feature = "rand"))]
+	#[must_use]
+	fn enclosing_points_seeded(points: &mut impl Deque<OPoint<T, D>>, rng: &mut impl Rng) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		let mut shuffled = Vec::with_capacity(points.len());
+		while let Some(point) = points.pop_front() {
+			shuffled.push(point);
+		}
+		shuffled.shuffle(rng);
+		for point in shuffled {
+			points.push_back(point);
+		}
+		Self::enclosing_points(points)
+	}
+	/// Returns minimum ball enclosing `points` without recursion.
+	///
+	/// Same as [`Self::enclosing_points()`] but maintains the recursion steps on the explicit
+	/// `stack` instead of the call stack, so no stack space is allocated on the heap by the `std`
+	/// feature. This makes large point sets feasible in `no_std` environments given a `stack` of
+	/// a capacity of the number of `points`. A `None` element marks a recursion step whose point
+	/// has been moved to the bounds. The `stack` is empty on return.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	fn enclosing_points_iterative(
+		points: &mut impl Deque<OPoint<T, D>>,
+		stack: &mut impl Deque<Option<OPoint<T, D>>>,
+	) -> Self
 	where
 		D: DimNameAdd<U1>,
 		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
 		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
 	{
 		assert!(!points.is_empty(), "empty point set");
+		if let Some(ball) = enclosing_collinear(points) {
+			return ball;
+		}
 		let mut bounds = OVec::<OPoint<T, D>, DimNameSum<D, U1>>::new();
 		(0..bounds.capacity())
+			.find_map(|_| enclosing_points_with_bounds_iterative(points, &mut bounds, stack))
+			.or_else(|| Self::enclosing_points_fallback(points))
+			.expect("numerical instability")
+	}
+	/// Moves points contained by ball to the back and other points to the front of `points`.
+	///
+	/// Returns the number of points not contained which are now at the front. This primes `points`
+	/// for a subsequent invocation of [`Self::enclosing_points()`] according to its move-to-front
+	/// heuristic, e.g., after adding new points to the ones a ball has been computed for.
+	fn partition_contained(&self, points: &mut impl Deque<OPoint<T, D>>) -> usize {
+		partition_contained(self, points)
+	}
+	/// Returns `previous` ball if it contains all `points`, otherwise minimum ball enclosing them.
+	///
+	/// Warm start for incremental workloads where `previous` has been computed for `points` of a
+	/// previous frame. It scans `points` once via [`Self::partition_contained()`] and returns
+	/// `previous` without any recursion if all are contained. Otherwise, the points not contained
+	/// are moved to the front before invoking [`Self::enclosing_points()`]. As the scan only
+	/// detects growth, `previous` is returned even if it is not minimal anymore, e.g., after
+	/// points on its surface have been removed.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	fn enclosing_points_warm(points: &mut impl Deque<OPoint<T, D>>, previous: &Self) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert!(!points.is_empty(), "empty point set");
+		if previous.partition_contained(points) == 0 {
+			previous.clone()
+		} else {
+			Self::enclosing_points(points)
+		}
+	}
+	/// Removes points within `epsilon` of a preceding point and returns the number of removed ones.
+	///
+	/// Near-duplicate points might fail to be contained due to floating-point inaccuracies and end
+	/// up as bounds of a degenerate circumscribed ball. This is an optional pre-pass for
+	/// [`Self::enclosing_points()`]. It keeps the order of the remaining points. Points are hashed
+	/// into a grid of cell size `epsilon` indexed by `floor(coordinate / epsilon)` as `i64`, so
+	/// only the points of neighboring cells are compared.
+	///
+	/// # Panics
+	///
+	/// Panics if `epsilon` is not positive or if a cell index is not representable as `i64`,
+	/// i.e., for points which are not finite or too far from the origin relative to `epsilon`.
+	#[cfg(feature = "std")]
+	#[allow(clippy::cast_possible_truncation)]
+	fn deduplicate_near(points: &mut VecDeque<OPoint<T, D>>, epsilon: T) -> usize {
+		assert!(epsilon > T::zero(), "non-positive epsilon");
+		// Offsets of neighboring cells including the cell itself.
+		let mut offsets = vec![Vec::with_capacity(D::USIZE)];
+		for _axis in 0..D::USIZE {
+			offsets = offsets
+				.into_iter()
+				.flat_map(|offset| {
+					[-1, 0, 1].map(|step| {
+						let mut offset = offset.clone();
+						offset.push(step);
+						offset
+					})
+				})
+				.collect();
+		}
+		let epsilon_squared = epsilon.clone() * epsilon.clone();
+		// Bounds of `i64` exactly representable as `f64`.
+		let (min, max) = (-(2f64.powi(63)), 2f64.powi(63));
+		let mut grid = HashMap::<Vec<i64>, Vec<usize>>::new();
+		let mut kept = Vec::with_capacity(points.len());
+		let count = points.len();
+		for point in points.drain(..) {
+			let cell = point
+				.iter()
+				.map(|coordinate| (coordinate.clone() / epsilon.clone()).floor())
+				.map(|index| {
+					try_convert::<T, f64>(index)
+						.filter(|index| (min..max).contains(index))
+						.expect("cell index out of range") as i64
+				})
+				.collect::<Vec<_>>();
+			let duplicate = offsets.iter().any(|offset| {
+				let neighbor = cell
+					.iter()
+					.zip(offset)
+					.map(|(index, step)| index.checked_add(*step))
+					.collect::<Option<Vec<_>>>();
+				neighbor
+					.and_then(|neighbor| grid.get(&neighbor))
+					.map_or(false, |indices| {
+						indices
+							.iter()
+							.any(|&index| (&kept[index] - &point).norm_squared() <= epsilon_squared)
+					})
+			});
+			if !duplicate {
+				grid.entry(cell).or_default().push(kept.len());
+				kept.push(point);
+			}
+		}
+		points.extend(kept);
+		count - points.len()
+	}
+	/// Returns minimum ball enclosing points given as structure of arrays.
+	///
+	/// The `i`-th column `coords[i]` holds the `i`-th coordinate of all points, e.g., as slice of
+	/// one `Vec<T>` per axis. Same as [`Self::enclosing_points_indexed()`] but gathers the points
+	/// of `indices` from the columns on demand, so only the bounds of each recursion step are
+	/// constructed as points. Afterwards, `indices` are in the order left by the move-to-front
+	/// heuristic.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of columns does not match the dimension, if the columns differ in
+	/// length, if `indices` is empty or out of bounds of the columns, or on numerical instability.
+	#[must_use]
+	fn enclosing_points_soa(coords: &[impl AsRef<[T]>], indices: &mut impl Deque<usize>) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert_eq!(coords.len(), D::USIZE, "mismatching dimension");
+		let len = coords.first().map_or(0, |column| column.as_ref().len());
+		assert!(
+			coords.iter().all(|column| column.as_ref().len() == len),
+			"mismatching columns"
+		);
+		enclosing_indices_by(
+			&|index| {
+				OPoint::from(OVector::<T, D>::from_fn(|row, _column| {
+					coords[row].as_ref()[index].clone()
+				}))
+			},
+			indices,
+		)
+	}
+	/// Returns minimum ball enclosing `points` and the axis-aligned bounding box of `points`.
+	///
+	/// The bounding box is returned as `(min, max)` of `points`, not of the ball. It is computed
+	/// during the traversal of [`Self::enclosing_points()`] by extending it with each point popped
+	/// from either end. Every point is popped at least once, at the latest by the pass checking
+	/// for non-finite and collinear points, avoiding a second pass.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	#[allow(clippy::type_complexity)]
+	fn enclosing_points_and_aabb(
+		points: &mut impl Deque<OPoint<T, D>>,
+	) -> (Self, (OPoint<T, D>, OPoint<T, D>))
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		let mut points = AabbDeque { points, aabb: None };
+		let ball = Self::enclosing_points(&mut points);
+		(ball, points.aabb.expect("empty point set"))
+	}
+	/// Returns minimum ball enclosing `points` and a score of its numerical stability.
+	///
+	/// The score within `[0, 1]` is the reciprocal condition number of the edge vectors spanning
+	/// the simplex of the bounds of the returned ball divided by the number of attempts
+	/// [`Self::enclosing_points()`] needed. The condition number is the square root of the ratio
+	/// of the largest to the smallest eigenvalue of their Gram matrix which is the matrix of the
+	/// linear system solved by [`Self::with_bounds()`]. A low score signals nearly degenerate
+	/// bounds (e.g., nearly collinear or coincident) or retries due to numerical instability, in
+	/// which case the result is less trustworthy and `points` might better be resampled. Collinear
+	/// `points` are enclosed like by [`Self::enclosing_points()`] within one attempt.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	fn enclosing_points_scored(points: &mut impl Deque<OPoint<T, D>>) -> (Self, T)
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert!(!points.is_empty(), "empty point set");
+		let collinear = enclosing_collinear::<T, D, Recorded<T, D, Self>>(points);
+		let (attempts, Recorded { ball, bounds }) = collinear.map_or_else(
+			|| {
+				let mut bounds = OVec::<OPoint<T, D>, DimNameSum<D, U1>>::new();
+				(1..=bounds.capacity())
+					.find_map(|attempts| {
+						maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
+							Recorded::<T, D, Self>::enclosing_points_with_bounds(
+								points,
+								&mut bounds,
+							)
+						})
+						.map(|recorded| (attempts, recorded))
+					})
+					.expect("numerical instability")
+			},
+			|recorded| (1, recorded),
+		);
+		let bounds = bounds.as_slice();
+		let length = bounds.len() - 1;
+		let score = if length == 0 {
+			T::one()
+		} else {
+			let gram = DMatrix::<T>::from_fn(length, length, |row, column| {
+				(&bounds[row + 1] - &bounds[0]).dot(&(&bounds[column + 1] - &bounds[0]))
+			});
+			let eigenvalues = gram.symmetric_eigenvalues();
+			(eigenvalues.min().max(T::zero()) / eigenvalues.max()).sqrt()
+		};
+		(ball, score / T::from_usize(attempts).unwrap())
+	}
+	/// Returns minimum ball enclosing `points` and the two bounds of maximum distance.
+	///
+	/// The bounds are the points on the surface the returned ball is circumscribed by. If there
+	/// are two of them, they are antipodal and realize the diameter. For more bounds in higher
+	/// dimensions, the pair is chosen by maximum separation among them. For a single bound, the
+	/// pair consists of this bound twice. Like [`Self::enclosing_points()`], collinear points are
+	/// bounded by the two extreme points along their line which realize the diameter.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	#[allow(clippy::type_complexity)]
+	fn enclosing_points_with_diameter(
+		points: &mut impl Deque<OPoint<T, D>>,
+	) -> (Self, (OPoint<T, D>, OPoint<T, D>))
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		let Recorded { ball, bounds } = Recorded::<T, D, Self>::enclosing_points(points);
+		let bounds = bounds.as_slice();
+		let mut pair = (&bounds[0], &bounds[0]);
+		let mut distance = T::zero();
+		for (index, a) in bounds.iter().enumerate() {
+			for b in &bounds[index + 1..] {
+				let norm_squared = (b - a).norm_squared();
+				if norm_squared > distance {
+					pair = (a, b);
+					distance = norm_squared;
+				}
+			}
+		}
+		let pair = (pair.0.clone(), pair.1.clone());
+		(ball, pair)
+	}
+	/// Returns minimum ball enclosing `points` and its support.
+	///
+	/// The support are the `1..=D + 1` points on the surface the returned ball is circumscribed by.
+	/// Like [`Self::enclosing_points()`], collinear points are supported by at most two points.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[must_use]
+	#[allow(clippy::type_complexity)]
+	fn enclosing_points_with_support(
+		points: &mut impl Deque<OPoint<T, D>>,
+	) -> (Self, OVec<OPoint<T, D>, DimNameSum<D, U1>>)
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		let Recorded { ball, bounds } = Recorded::<T, D, Self>::enclosing_points(points);
+		(ball, bounds)
+	}
+	/// Returns minimum ball enclosing `points` and statistics of its computation.
+	///
+	/// Same as [`Self::enclosing_points()`] but counts the invocations of [`Self::with_bounds()`]
+	/// and tracks the maximum recursion depth and whether the stack red zone has been entered
+	/// across all attempts. This helps to empirically assess the cost of point orders.
+	///
+	/// # Panics
+	///
+	/// Panics if `points` is empty or on numerical instability.
+	#[cfg(feature = "std")]
+	#[must_use]
+	fn enclosing_points_with_stats(points: &mut impl Deque<OPoint<T, D>>) -> (Self, EnclosingStats)
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert!(!points.is_empty(), "empty point set");
+		let mut stats = EnclosingStats::default();
+		if let Some(ball) = enclosing_collinear(points) {
+			stats.with_bounds_calls = 1;
+			return (ball, stats);
+		}
+		let mut bounds = OVec::<OPoint<T, D>, DimNameSum<D, U1>>::new();
+		let ball = (0..bounds.capacity())
 			.find_map(|_| {
 				maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
-					Self::enclosing_points_with_bounds(points, &mut bounds)
+					enclosing_with_bounds(points, &mut bounds, &mut Stats(&mut stats), 0)
 				})
 			})
-			.expect("numerical instability")
+			.or_else(|| Self::enclosing_points_fallback(points))
+			.expect("numerical instability");
+		(ball, stats)
+	}
+	/// Returns minimum ball enclosing `points` reordered by `permutation` beforehand.
+	///
+	/// The `i`-th point is replaced by the `permutation[i]`-th point before invoking
+	/// [`Self::enclosing_points()`]. This replaces randomly permuting `points` with a controlled
+	/// order to reproduce results. Afterwards, `points` are in the order left by the move-to-front
+	/// heuristic.
+	///
+	/// # Panics
+	///
+	/// Panics if `permutation` is not a permutation of the indices of `points`, if `points` is
+	/// empty, or on numerical instability.
+	#[cfg(feature = "std")]
+	#[must_use]
+	fn enclosing_points_permuted(points: &mut Vec<OPoint<T, D>>, permutation: &[usize]) -> Self
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert_eq!(points.len(), permutation.len(), "invalid permutation");
+		let mut permuted = vec![false; permutation.len()];
+		for &index in permutation {
+			assert!(
+				!replace(permuted.get_mut(index).expect("invalid permutation"), true),
+				"invalid permutation"
+			);
+		}
+		let mut permuted = permutation
+			.iter()
+			.map(|&index| points[index].clone())
+			.collect::<VecDeque<_>>();
+		let ball = Self::enclosing_points(&mut permuted);
+		*points = permuted.into();
+		ball
 	}
-	/// Returns minimum ball enclosing `points` with `bounds`.
+	/// Returns minimum balls enclosing clusters of `points` grouped by their `labels`.
+	///
+	/// Returns one ball per distinct label in ascending order of labels. The clusters are gathered
+	/// one after another into the same scratch deque, so only one allocation is made for all of
+	/// them.
 	///
-	/// Recursive helper for [`Self::enclosing_points()`].
-	#[doc(hidden)]
+	/// # Panics
+	///
+	/// Panics if `points` and `labels` differ in length.
+	///
+	/// # Example
+	///
+	/// Finds minimum 2-balls enclosing two clusters of points:
+	///
+	/// ```
+	/// use miniball::{nalgebra::Point2, Ball, Enclosing};
+	///
+	/// let points = [
+	/// 	Point2::new(-4.0, 0.0),
+	/// 	Point2::new(3.0, 0.0),
+	/// 	Point2::new(-2.0, 0.0),
+	/// 	Point2::new(5.0, 0.0),
+	/// ];
+	/// let labels = [0, 1, 0, 1];
+	/// let balls = Ball::enclosing_clustered(&points, &labels);
+	/// assert_eq!(balls.len(), 2);
+	/// assert_eq!(balls[0].center, Point2::new(-3.0, 0.0));
+	/// assert_eq!(balls[1].center, Point2::new(4.0, 0.0));
+	/// ```
+	#[cfg(feature = "std")]
+	#[must_use]
+	fn enclosing_clustered(points: &[OPoint<T, D>], labels: &[usize]) -> Vec<Self>
+	where
+		D: DimNameAdd<U1>,
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		assert_eq!(points.len(), labels.len(), "mismatching labels");
+		let mut clusters = labels.to_vec();
+		clusters.sort_unstable();
+		clusters.dedup();
+		let mut scratch = VecDeque::with_capacity(points.len());
+		clusters
+			.into_iter()
+			.map(|cluster| {
+				scratch.clear();
+				scratch.extend(
+					points
+						.iter()
+						.zip(labels)
+						.filter(|&(_point, &label)| label == cluster)
+						.map(|(point, _label)| point.clone()),
+				);
+				Self::enclosing_points(&mut scratch)
+			})
+			.collect()
+	}
+	/// Returns minimum ball enclosing `points` with `bounds` on its surface.
+	///
+	/// Recursion step of [`Self::enclosing_points()`] invoking itself for the remaining `points`,
+	/// so overriding it affects each step, see the implementing section of [`Enclosing`]. It is
+	/// also the entry point into the move-to-front recursion for custom algorithms, e.g., to
+	/// prescribe `bounds` which must be on the surface of the returned ball as does
+	/// `enclosing_points_pivot()` of the `std` feature. Returns `None` if [`Self::with_bounds()`]
+	/// fails for any combination of `bounds`. The length of `points` and `bounds` is conserved.
+	///
+	/// Never exceeds the capacity of `bounds`. Once `bounds` are full, they uniquely define the
+	/// circumscribed ball, so the remaining `points` are treated as contained without being taken
+	/// from `points`. Near-duplicate points failing [`Self::contains()`] due to floating-point
+	/// inaccuracies can therefore neither overflow `bounds` nor get lost.
 	#[must_use]
 	fn enclosing_points_with_bounds(
 		points: &mut impl Deque<OPoint<T, D>>,
@@ -178,30 +908,609 @@ where
 		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
 		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
 	{
-		// Take point from back.
-		if let Some(point) = points.pop_back().filter(|_| !bounds.is_full()) {
-			let ball = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
-				// Branch with one point less.
-				Self::enclosing_points_with_bounds(points, bounds)
+		enclosing_with_bounds(points, bounds, &mut Steps, 0)
+	}
+}
+
+/// Returns minimum ball enclosing `points` as by `contains`.
+///
+/// Helper for [`Enclosing::enclosing_points()`] and its variants using another containment
+/// tolerance than [`Enclosing::contains()`].
+///
+/// # Panics
+///
+/// Panics if `points` is empty or on numerical instability.
+pub fn enclosing_points_by<T: RealField, D: DimNameAdd<U1>, E: Enclosing<T, D>>(
+	points: &mut impl Deque<OPoint<T, D>>,
+	contains: &impl Fn(&E, &OPoint<T, D>) -> bool,
+) -> E
+where
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	try_enclosing_points_by(points, &mut Points(contains)).unwrap_or_else(|error| panic!("{error}"))
+}
+
+/// Returns minimum ball enclosing `points` as by recursion steps of `hooks` or an error.
+///
+/// Helper for [`Enclosing::try_enclosing_points()`] and [`enclosing_points_by()`].
+fn try_enclosing_points_by<T: RealField, D: DimNameAdd<U1>, E: Enclosing<T, D>>(
+	points: &mut impl Deque<OPoint<T, D>>,
+	hooks: &mut impl Hooks<T, D, E, Item = OPoint<T, D>>,
+) -> Result<E, EnclosingError>
+where
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	if points.is_empty() {
+		return Err(EnclosingError::EmptyPointSet);
+	}
+	let mut bounds = OVec::<OPoint<T, D>, DimNameSum<D, U1>>::new();
+	(0..bounds.capacity())
+		.find_map(|_| {
+			maybe_grow(E::RED_ZONE, E::STACK_SIZE, || {
+				hooks.recurse(points, &mut bounds, 0)
+			})
+		})
+		.or_else(|| E::enclosing_points_fallback(points))
+		.ok_or(EnclosingError::NumericalInstability)
+}
+
+/// Returns minimum ball enclosing the points of `indices` as accessed by `point`.
+///
+/// Helper for [`Enclosing::enclosing_points_indexed()`], [`Enclosing::enclosing_points_flat()`],
+/// and [`Enclosing::enclosing_points_soa()`].
+///
+/// # Panics
+///
+/// Panics if `indices` is empty or on numerical instability.
+fn enclosing_indices_by<T, D, E, P>(
+	point: &impl Fn(usize) -> P,
+	indices: &mut impl Deque<usize>,
+) -> E
+where
+	T: RealField,
+	D: DimNameAdd<U1>,
+	E: Enclosing<T, D>,
+	P: Borrow<OPoint<T, D>>,
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	let origin = indices.pop_front().expect("empty point set");
+	indices.push_front(origin);
+	if let Some(ball) = enclosing_collinear_by(point(origin).borrow().clone(), |visit| {
+		deque::for_each(indices, |&index| visit(point(index).borrow()));
+	}) {
+		return ball;
+	}
+	let mut bounds = OVec::<OPoint<T, D>, DimNameSum<D, U1>>::new();
+	let ball = (0..bounds.capacity()).find_map(|_| {
+		maybe_grow(E::RED_ZONE, E::STACK_SIZE, || {
+			enclosing_with_bounds(indices, &mut bounds, &mut Indices(point), 0)
+		})
+	});
+	#[cfg(feature = "std")]
+	let ball = ball.or_else(|| {
+		let mut points_of_indices = VecDeque::with_capacity(indices.len());
+		deque::for_each(indices, |&index| {
+			points_of_indices.push_back(point(index).borrow().clone());
+		});
+		E::enclosing_points_fallback(&mut points_of_indices)
+	});
+	ball.expect("numerical instability")
+}
+
+/// Hooks of [`enclosing_with_bounds()`] parameterizing its recursion.
+///
+/// Implemented by [`Steps`] and [`Points`] moving points and by [`Indices`] moving their indices,
+/// and by `Stats` of the `std` feature recording statistics.
+trait Hooks<T: RealField, D: DimNameAdd<U1>, E: Enclosing<T, D>>
+where
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	/// Item moved by the move-to-front heuristic.
+	type Item;
+	/// Part of item kept while its point is one of the bounds.
+	type Kept;
+
+	/// Whether `ball` contains `item` or its point coincides with one of the `bounds`.
+	fn contains(&self, ball: &E, item: &Self::Item, bounds: &[OPoint<T, D>]) -> bool;
+	/// Moves point of `item` to `bounds` and returns the part of it to be kept.
+	fn push_bound(
+		&self,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		item: Self::Item,
+	) -> Self::Kept;
+	/// Removes last point of `bounds` and restores its item from the `kept` part of it.
+	fn pop_bound(
+		&self,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		kept: Self::Kept,
+	) -> Self::Item;
+	/// Invoked when entering recursion step at `depth`.
+	fn enter(&mut self, _depth: usize) {}
+	/// Returns circumscribed ball with all `bounds` on surface or `None` if it does not exist.
+	fn with_bounds(&mut self, bounds: &[OPoint<T, D>]) -> Option<E> {
+		E::with_bounds(bounds)
+	}
+	/// Returns minimum ball enclosing `items` with `bounds` by recursion step at `depth`.
+	fn recurse(
+		&mut self,
+		items: &mut impl Deque<Self::Item>,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		depth: usize,
+	) -> Option<E>
+	where
+		Self: Sized,
+	{
+		enclosing_with_bounds(items, bounds, self, depth)
+	}
+}
+
+/// Returns minimum ball enclosing `items` with `bounds` at recursion `depth`.
+///
+/// Recursive step of Welzl's algorithm with move-to-front heuristic shared by all its variants,
+/// see [`Enclosing::enclosing_points_with_bounds()`]. Their differences are confined to `hooks`.
+fn enclosing_with_bounds<T, D, E, H>(
+	items: &mut impl Deque<H::Item>,
+	bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+	hooks: &mut H,
+	depth: usize,
+) -> Option<E>
+where
+	T: RealField,
+	D: DimNameAdd<U1>,
+	E: Enclosing<T, D>,
+	H: Hooks<T, D, E>,
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	hooks.enter(depth);
+	// Length of deque to be conserved by each recursion step.
+	let len = items.len();
+	// Take item from back unless bounds are full as it would get lost otherwise.
+	let item = if bounds.is_full() {
+		None
+	} else {
+		items.pop_back()
+	};
+	if let Some(item) = item {
+		let ball = maybe_grow(E::RED_ZONE, E::STACK_SIZE, || {
+			// Branch with one point less.
+			hooks.recurse(items, bounds, depth + 1)
+		});
+		// Point coinciding with bound is on surface but would render bounds affinely dependent.
+		if let Some(ball) = ball.filter(|ball| hooks.contains(ball, &item, bounds.as_slice())) {
+			// Move item to back.
+			items.push_back(item);
+			debug_assert_eq!(items.len(), len, "deque length not conserved");
+			Some(ball)
+		} else {
+			// Move point to bounds.
+			let kept = hooks.push_bound(bounds, item);
+			let ball = maybe_grow(E::RED_ZONE, E::STACK_SIZE, || {
+				// Branch with one point less and one bound more.
+				hooks.recurse(items, bounds, depth + 1)
 			});
-			if let Some(ball) = ball.filter(|ball| ball.contains(&point)) {
-				// Move point to back.
-				points.push_back(point);
-				Some(ball)
+			// Move item to front.
+			items.push_front(hooks.pop_bound(bounds, kept));
+			debug_assert_eq!(items.len(), len, "deque length not conserved");
+			ball
+		}
+	} else {
+		// Circumscribed ball with bounds.
+		hooks.with_bounds(bounds.as_slice())
+	}
+}
+
+/// Hooks moving points whose recursion steps are [`Enclosing::enclosing_points_with_bounds()`].
+///
+/// Helper for [`Enclosing::enclosing_points_with_bounds()`] and
+/// [`Enclosing::try_enclosing_points()`], so overriding the former affects each step.
+struct Steps;
+
+impl<T, D, E> Hooks<T, D, E> for Steps
+where
+	T: RealField,
+	D: DimNameAdd<U1>,
+	E: Enclosing<T, D>,
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	type Item = OPoint<T, D>;
+	type Kept = ();
+
+	fn contains(&self, ball: &E, point: &OPoint<T, D>, bounds: &[OPoint<T, D>]) -> bool {
+		ball.contains(point) || bounds.contains(point)
+	}
+	fn push_bound(&self, bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>, point: OPoint<T, D>) {
+		bounds.push(point);
+	}
+	fn pop_bound(
+		&self,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		_kept: (),
+	) -> OPoint<T, D> {
+		bounds.pop().unwrap()
+	}
+	fn recurse(
+		&mut self,
+		points: &mut impl Deque<OPoint<T, D>>,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		_depth: usize,
+	) -> Option<E> {
+		E::enclosing_points_with_bounds(points, bounds)
+	}
+}
+
+/// Hooks moving points and testing their containment by a closure.
+///
+/// Helper for [`enclosing_points_by()`].
+struct Points<F>(F);
+
+impl<T, D, E, F> Hooks<T, D, E> for Points<F>
+where
+	T: RealField,
+	D: DimNameAdd<U1>,
+	E: Enclosing<T, D>,
+	F: Fn(&E, &OPoint<T, D>) -> bool,
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	type Item = OPoint<T, D>;
+	type Kept = ();
+
+	fn contains(&self, ball: &E, point: &OPoint<T, D>, bounds: &[OPoint<T, D>]) -> bool {
+		(self.0)(ball, point) || bounds.contains(point)
+	}
+	fn push_bound(&self, bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>, point: OPoint<T, D>) {
+		bounds.push(point);
+	}
+	fn pop_bound(
+		&self,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		_kept: (),
+	) -> OPoint<T, D> {
+		bounds.pop().unwrap()
+	}
+}
+
+/// Hooks moving indices of points as accessed by a closure.
+///
+/// Helper for [`enclosing_indices_by()`].
+struct Indices<F>(F);
+
+impl<T, D, E, F, P> Hooks<T, D, E> for Indices<F>
+where
+	T: RealField,
+	D: DimNameAdd<U1>,
+	E: Enclosing<T, D>,
+	F: Fn(usize) -> P,
+	P: Borrow<OPoint<T, D>>,
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	type Item = usize;
+	type Kept = usize;
+
+	fn contains(&self, ball: &E, &index: &usize, bounds: &[OPoint<T, D>]) -> bool {
+		let point = (self.0)(index);
+		let point = point.borrow();
+		ball.contains(point) || bounds.contains(point)
+	}
+	fn push_bound(
+		&self,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		index: usize,
+	) -> usize {
+		bounds.push((self.0)(index).borrow().clone());
+		index
+	}
+	fn pop_bound(&self, bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>, index: usize) -> usize {
+		bounds.pop();
+		index
+	}
+}
+
+/// Hooks moving points while recording statistics of the recursion.
+///
+/// Helper for [`Enclosing::enclosing_points_with_stats()`].
+#[cfg(feature = "std")]
+struct Stats<'a>(&'a mut EnclosingStats);
+
+#[cfg(feature = "std")]
+impl<T, D, E> Hooks<T, D, E> for Stats<'_>
+where
+	T: RealField,
+	D: DimNameAdd<U1>,
+	E: Enclosing<T, D>,
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	type Item = OPoint<T, D>;
+	type Kept = ();
+
+	fn contains(&self, ball: &E, point: &OPoint<T, D>, bounds: &[OPoint<T, D>]) -> bool {
+		ball.contains(point) || bounds.contains(point)
+	}
+	fn push_bound(&self, bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>, point: OPoint<T, D>) {
+		bounds.push(point);
+	}
+	fn pop_bound(
+		&self,
+		bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+		_kept: (),
+	) -> OPoint<T, D> {
+		bounds.pop().unwrap()
+	}
+	fn enter(&mut self, depth: usize) {
+		self.0.max_depth = self.0.max_depth.max(depth);
+		self.0.red_zone_entered |= remaining_stack().map_or(false, |stack| stack < E::RED_ZONE);
+	}
+	fn with_bounds(&mut self, bounds: &[OPoint<T, D>]) -> Option<E> {
+		self.0.with_bounds_calls += 1;
+		E::with_bounds(bounds)
+	}
+}
+
+/// Returns minimum ball enclosing `points` with `bounds` using an explicit `stack`.
+///
+/// Unrolls the recursion of [`enclosing_with_bounds()`]. Each recursion step pushes the
+/// point it takes from the back of `points` onto `stack` and replaces it by `None` when moving the
+/// point to `bounds` for its second branch.
+///
+/// Helper for [`Enclosing::enclosing_points_iterative()`].
+fn enclosing_points_with_bounds_iterative<T: RealField, D: DimNameAdd<U1>, E: Enclosing<T, D>>(
+	points: &mut impl Deque<OPoint<T, D>>,
+	bounds: &mut OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+	stack: &mut impl Deque<Option<OPoint<T, D>>>,
+) -> Option<E>
+where
+	DefaultAllocator:
+		Allocator<T, D> + Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	let depth = stack.len();
+	loop {
+		// Descend into branches with one point less.
+		while !bounds.is_full() {
+			if let Some(point) = points.pop_back() {
+				stack.push_back(Some(point));
 			} else {
-				// Move point to bounds.
-				bounds.push(point);
-				let ball = maybe_grow(Self::RED_ZONE, Self::STACK_SIZE, || {
-					// Branch with one point less and one bound more.
-					Self::enclosing_points_with_bounds(points, bounds)
-				});
-				// Move point to front.
-				points.push_front(bounds.pop().unwrap());
-				ball
+				break;
+			}
+		}
+		// Circumscribed ball with bounds.
+		let mut ball = E::with_bounds(bounds.as_slice());
+		// Ascend until a branch with one bound more is pending.
+		loop {
+			if stack.len() == depth {
+				return ball;
+			}
+			match stack.pop_back().unwrap() {
+				Some(point) => {
+					// Point coinciding with bound is on surface but would render bounds
+					// affinely dependent.
+					if let Some(contained) = ball
+						.filter(|ball| ball.contains(&point) || bounds.as_slice().contains(&point))
+					{
+						// Move point to back.
+						points.push_back(point);
+						ball = Some(contained);
+					} else {
+						// Move point to bounds.
+						bounds.push(point);
+						stack.push_back(None);
+						break;
+					}
+				}
+				None => {
+					// Move point to front.
+					points.push_front(bounds.pop().unwrap());
+				}
+			}
+		}
+	}
+}
+
+/// Returns minimum ball enclosing `points` if they are collinear or `None` otherwise.
+///
+/// The affine hull of collinear points has rank 1 (or 0 for identical points) which makes the
+/// circumscribed balls of Welzl's algorithm numerically degenerate. Instead, the ball is
+/// circumscribed by the two extreme points along the line. Points are considered collinear if
+/// each point's squared distance to the line is at most `L² √ε / 4` where `L` is the distance of
+/// the first point to the farthest point, so the ball contains them as by [`Enclosing::contains()`].
+///
+/// Helper for [`Enclosing::enclosing_points()`].
+fn enclosing_collinear<T: RealField, D: DimName, E: Enclosing<T, D>>(
+	points: &mut impl Deque<OPoint<T, D>>,
+) -> Option<E>
+where
+	DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+	let origin = points.pop_front()?;
+	points.push_front(origin.clone());
+	enclosing_collinear_by(origin, |visit| deque::for_each(points, visit))
+}
+
+/// Returns minimum ball enclosing the points visited by `for_each` if they are collinear with
+/// `origin` or `None` otherwise.
+///
+/// Helper for [`enclosing_collinear()`], [`enclosing_indices_by()`], and
+/// [`Enclosing::try_enclosing_points()`] visiting each point once.
+fn enclosing_collinear_by<T: RealField, D: DimName, E: Enclosing<T, D>>(
+	origin: OPoint<T, D>,
+	mut for_each: impl FnMut(&mut dyn FnMut(&OPoint<T, D>)),
+) -> Option<E>
+where
+	DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+	// Line spanned by origin and point farthest from origin so far.
+	let mut direction = OVector::<T, D>::zeros();
+	let mut length_squared = T::zero();
+	let four = T::from_usize(4).unwrap();
+	let mut collinear = true;
+	let mut min = (origin.clone(), T::zero());
+	let mut max = min.clone();
+	for_each(&mut |point| {
+		if !collinear {
+			return;
+		}
+		let vector = point - &origin;
+		let projection = vector.dot(&direction);
+		let norm_squared = vector.norm_squared();
+		// Scaled by `L⁴` to compare rejections scaled by `L²`.
+		let tolerance =
+			length_squared.clone() * length_squared.clone() * T::default_epsilon().sqrt();
+		let rejection = length_squared.clone() * norm_squared.clone() - projection.clone().powi(2);
+		collinear &= rejection * four.clone() <= tolerance;
+		if norm_squared > length_squared {
+			// Extend line and reproject extreme points onto it.
+			length_squared = norm_squared;
+			direction = vector;
+			min.1 = (&min.0 - &origin).dot(&direction);
+			max.1 = (&max.0 - &origin).dot(&direction);
+			if min.1 > max.1 {
+				core::mem::swap(&mut min, &mut max);
 			}
+			max = (point.clone(), length_squared.clone());
+		} else if projection < min.1 {
+			min = (point.clone(), projection);
+		} else if projection > max.1 {
+			max = (point.clone(), projection);
+		}
+	});
+	if length_squared <= T::zero() {
+		// Identical points.
+		return E::with_bounds(&[origin]);
+	}
+	collinear.then(|| E::with_bounds(&[min.0, max.0])).flatten()
+}
+
+/// Moves points contained by `ball` to the back and other points to the front of `points`.
+///
+/// Recursive helper for [`Enclosing::partition_contained()`].
+fn partition_contained<T: RealField, D: DimName, E: Enclosing<T, D>>(
+	ball: &E,
+	points: &mut impl Deque<OPoint<T, D>>,
+) -> usize
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	// Take point from back.
+	points.pop_back().map_or(0, |point| {
+		let count = maybe_grow(E::RED_ZONE, E::STACK_SIZE, || {
+			// Partition remaining points.
+			partition_contained(ball, points)
+		});
+		if ball.contains(&point) {
+			// Move point to back.
+			points.push_back(point);
+			count
 		} else {
-			// Circumscribed ball with bounds.
-			Self::with_bounds(bounds.as_slice())
+			// Move point to front.
+			points.push_front(point);
+			count + 1
 		}
+	})
+}
+
+/// Deque adapter extending the axis-aligned bounding box by each point taken from either end.
+///
+/// Helper for [`Enclosing::enclosing_points_and_aabb()`].
+struct AabbDeque<'a, T: RealField, D: DimName, Q>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	points: &'a mut Q,
+	aabb: Option<(OPoint<T, D>, OPoint<T, D>)>,
+}
+
+impl<T: RealField, D: DimName, Q> AabbDeque<'_, T, D, Q>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	/// Extends bounding box by `point` and passes it through.
+	fn extend(&mut self, point: Option<OPoint<T, D>>) -> Option<OPoint<T, D>> {
+		let point = point?;
+		self.aabb = Some(self.aabb.take().map_or_else(
+			|| (point.clone(), point.clone()),
+			|(min, max)| (min.inf(&point), max.sup(&point)),
+		));
+		Some(point)
+	}
+}
+
+impl<T: RealField, D: DimName, Q: Deque<OPoint<T, D>>> Deque<OPoint<T, D>>
+	for AabbDeque<'_, T, D, Q>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	#[inline]
+	fn len(&self) -> usize {
+		self.points.len()
+	}
+
+	#[inline]
+	fn pop_front(&mut self) -> Option<OPoint<T, D>> {
+		let point = self.points.pop_front();
+		self.extend(point)
+	}
+	#[inline]
+	fn pop_back(&mut self) -> Option<OPoint<T, D>> {
+		let point = self.points.pop_back();
+		self.extend(point)
+	}
+
+	#[inline]
+	fn push_front(&mut self, value: OPoint<T, D>) {
+		self.points.push_front(value);
+	}
+	#[inline]
+	fn push_back(&mut self, value: OPoint<T, D>) {
+		self.points.push_back(value);
+	}
+}
+
+/// Ball recording the bounds it has been circumscribed by.
+///
+/// Helper for [`Enclosing::enclosing_points_scored()`],
+/// [`Enclosing::enclosing_points_with_diameter()`], and
+/// [`Enclosing::enclosing_points_with_support()`].
+#[derive(Clone)]
+struct Recorded<T: RealField, D: DimNameAdd<U1>, E>
+where
+	DefaultAllocator: Allocator<T, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	ball: E,
+	bounds: OVec<OPoint<T, D>, DimNameSum<D, U1>>,
+}
+
+impl<T: RealField, D: DimNameAdd<U1>, E: Enclosing<T, D>> Enclosing<T, D> for Recorded<T, D, E>
+where
+	DefaultAllocator: Allocator<T, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+	<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+{
+	#[inline]
+	fn contains(&self, point: &OPoint<T, D>) -> bool {
+		self.ball.contains(point)
+	}
+	fn with_bounds(bounds: &[OPoint<T, D>]) -> Option<Self>
+	where
+		DefaultAllocator: Allocator<T, D, D>,
+	{
+		let bounds = OVec::try_from(bounds).ok()?;
+		E::with_bounds(bounds.as_slice()).map(|ball| Self { ball, bounds })
 	}
 }
@@ -0,0 +1,71 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::fmt::{self, Display, Formatter};
+
+/// Error of [`Enclosing::try_enclosing_points()`](crate::Enclosing::try_enclosing_points).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnclosingError {
+	/// Point set is empty.
+	EmptyPointSet,
+	/// Point set contains a point with infinite or NaN coordinates.
+	NonFinitePoint,
+	/// All attempts of computing circumscribed balls failed and there is no fallback.
+	NumericalInstability,
+}
+
+impl Display for EnclosingError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::EmptyPointSet => "empty point set",
+			Self::NonFinitePoint => "non-finite point",
+			Self::NumericalInstability => "numerical instability",
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EnclosingError {}
+
+/// Error of [`Ball::with_bounds_result()`](crate::Ball::with_bounds_result).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WithBoundsError {
+	/// Bounds are empty.
+	EmptyBounds,
+	/// More bounds than one more than dimensions.
+	TooManyBounds,
+	/// Bounds are affinely dependent, so the circumscribed ball is not unique or does not exist.
+	AffinelyDependent,
+	/// Bounds or radius of circumscribed ball are not finite.
+	NonFinite,
+}
+
+impl Display for WithBoundsError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::EmptyBounds => "empty bounds",
+			Self::TooManyBounds => "too many bounds",
+			Self::AffinelyDependent => "affinely dependent bounds",
+			Self::NonFinite => "non-finite bounds or radius",
+		})
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WithBoundsError {}
+
+/// Error of exceeding the capacity of [`OVec`](crate::OVec).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CapacityError;
+
+impl Display for CapacityError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		f.write_str("insufficient capacity")
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
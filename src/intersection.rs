@@ -0,0 +1,18 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Kind of intersection of two balls, see [`Ball::intersection_kind()`](crate::Ball::intersection_kind).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Intersection {
+	/// Balls are apart.
+	Disjoint,
+	/// Balls touch each other from outside.
+	Touching,
+	/// Balls overlap but neither contains the other.
+	Overlapping,
+	/// One ball contains the other including touching it from inside.
+	Contained,
+}
@@ -8,16 +8,31 @@
 //!
 //!   * Finds circumscribed *n*-ball of set of bounds, see [`Enclosing::with_bounds()`].
 //!   * Finds minimum *n*-ball enclosing set of points, see [`Enclosing::enclosing_points()`].
+//!   * Finds minimum *n*-ball enclosing set of *n*-balls, see [`Ball::enclosing_balls()`].
+//!   * Finds minimum *n*-ball of dimension determined at runtime, see [`DynBall`].
+//!   * Finds approximate minimum *n*-ball of streamed points in constant memory, see
+//!     [`OnlineBall`].
+//!   * Finds minimum-volume enclosing *n*-ellipsoid, see [`Ellipsoid::enclosing_points()`].
 //!
 //! # Roadmap
 //!
-//!   * Find minimum enclosing *n*-ball of *n*-balls.
-//!   * Find minimum-volume enclosing *n*-ellipsoid.
 //!   * Improve numerical stability and performance.
 //!
 //! # Features
 //!
 //!   * `std` for spilling recursion stack over to the heap if necessary. Enabled by `default`.
+//!   * `rand` for randomized methods.
+//!   * `rayon` for parallel methods. Implies `std`.
+//!   * `serde` for serializing and deserializing balls.
+//!   * `approx` for comparing balls approximately via `AbsDiffEq` and `RelativeEq`.
+//!   * `arrayvec` for implementing [`Deque`] for `ArrayVec` without `std`.
+//!   * `heapless` for implementing [`Deque`] for `heapless::Deque` without allocator.
+//!   * `parry` for converting `f32` balls from and to shapes of `parry2d` and `parry3d`. Implies
+//!     `std`.
+//!   * `robust` for circumscribing nearly degenerate 2-balls and 3-balls with exact predicates.
+//!
+//! The minimum supported Rust version covers the default features. Optional dependencies may
+//! require a newer toolchain, e.g., `rayon` or `parry` which requires Rust 1.65.
 
 #![forbid(unsafe_code)]
 #![forbid(missing_docs)]
@@ -27,12 +42,38 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 mod ball;
+mod ball_with_radius;
 mod deque;
+#[cfg(feature = "std")]
+mod dyn_ball;
+mod ellipsoid;
 mod enclosing;
+mod error;
+mod intersection;
+mod location;
+#[cfg(feature = "std")]
+mod min_ball;
+mod online_ball;
 mod ovec;
+#[cfg(feature = "parry")]
+mod parry;
+#[cfg(feature = "std")]
+mod stats;
 
-pub use ball::Ball;
+pub use ball::{Ball, Ball2, Ball3, Ball4};
+pub use ball_with_radius::BallWithRadius;
 pub use deque::Deque;
+#[cfg(feature = "std")]
+pub use dyn_ball::DynBall;
+pub use ellipsoid::Ellipsoid;
 pub use enclosing::Enclosing;
+pub use error::{CapacityError, EnclosingError, WithBoundsError};
+pub use intersection::Intersection;
+pub use location::Location;
+#[cfg(feature = "std")]
+pub use min_ball::MinBall;
 pub use nalgebra;
-use ovec::OVec;
+pub use online_ball::OnlineBall;
+pub use ovec::OVec;
+#[cfg(feature = "std")]
+pub use stats::EnclosingStats;
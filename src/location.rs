@@ -0,0 +1,16 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Location of point relative to ball, see [`Ball::locate()`](crate::Ball::locate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+	/// Point is strictly inside the ball.
+	Inside,
+	/// Point is on the surface within tolerance.
+	Surface,
+	/// Point is outside the ball.
+	Outside,
+}
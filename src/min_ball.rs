@@ -0,0 +1,155 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Ball, Enclosing};
+use nalgebra::{
+	base::allocator::Allocator, DefaultAllocator, DimNameAdd, DimNameSum, OPoint, RealField, U1,
+};
+use std::collections::VecDeque;
+
+/// Accumulator of streamed points for their minimum enclosing ball.
+///
+/// Buffers pushed points in a deque primed for the move-to-front heuristic of
+/// [`Enclosing::enclosing_points()`] which is run by [`Self::finish()`]. Meanwhile, it maintains
+/// a running ball enclosing all points pushed so far, see [`Self::ball()`]. It implements
+/// [`Extend`] and [`FromIterator`], so points can be collected into it:
+///
+/// ```
+/// use miniball::{nalgebra::Point2, MinBall};
+///
+/// let ball = [(-1.0, 0.0), (1.0, 0.0), (0.0, 0.5)]
+/// 	.map(|(x, y)| Point2::<f64>::new(x, y))
+/// 	.into_iter()
+/// 	.collect::<MinBall<_, _>>()
+/// 	.finish();
+/// assert_eq!(ball.center, Point2::origin());
+/// assert_eq!(ball.radius_squared, 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinBall<T: RealField, D: DimNameAdd<U1>>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	points: VecDeque<OPoint<T, D>>,
+	ball: Option<Ball<T, D>>,
+	exact: bool,
+}
+
+impl<T: RealField, D: DimNameAdd<U1>> MinBall<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	/// New accumulator without points.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			points: VecDeque::new(),
+			ball: None,
+			exact: true,
+		}
+	}
+	/// Number of points pushed so far.
+	#[must_use]
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.points.len()
+	}
+	/// Whether no points have been pushed so far.
+	#[must_use]
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.points.is_empty()
+	}
+	/// Pushes `point`.
+	///
+	/// If the running ball contains `point`, it remains unchanged as the minimum ball of a point
+	/// set does not change by adding a point it contains. Otherwise, the running ball is grown to
+	/// contain `point` as by [`Ball::expand_to_contain()`] and `point` is moved to the front of
+	/// the buffer, so it is considered first by [`Self::finish()`].
+	pub fn push(&mut self, point: OPoint<T, D>) {
+		match &mut self.ball {
+			Some(ball) if ball.contains(&point) => self.points.push_back(point),
+			Some(ball) => {
+				ball.expand_to_contain(&point);
+				self.exact = false;
+				self.points.push_front(point);
+			}
+			None => {
+				self.ball = Some(Ball {
+					center: point.clone(),
+					radius_squared: T::zero(),
+				});
+				self.points.push_front(point);
+			}
+		}
+	}
+	/// Returns running ball enclosing all points pushed so far or `None` if there are none.
+	///
+	/// This is the minimum ball if [`Self::is_exact()`], otherwise merely an enclosing one.
+	#[must_use]
+	#[inline]
+	pub const fn ball(&self) -> Option<&Ball<T, D>> {
+		self.ball.as_ref()
+	}
+	/// Whether the running ball is the minimum ball of all points pushed so far.
+	///
+	/// This is the case until a point outside of the running ball is pushed.
+	#[must_use]
+	#[inline]
+	pub const fn is_exact(&self) -> bool {
+		self.exact
+	}
+	/// Returns minimum ball enclosing all points pushed so far.
+	///
+	/// Returns the running ball if [`Self::is_exact()`], otherwise invokes
+	/// [`Enclosing::enclosing_points()`] with the buffered points.
+	///
+	/// # Panics
+	///
+	/// Panics if no points have been pushed or on numerical instability.
+	#[must_use]
+	pub fn finish(mut self) -> Ball<T, D>
+	where
+		DefaultAllocator: Allocator<T, D, D> + Allocator<OPoint<T, D>, DimNameSum<D, U1>>,
+		<DefaultAllocator as Allocator<OPoint<T, D>, DimNameSum<D, U1>>>::Buffer: Default,
+	{
+		match self.ball {
+			Some(ball) if self.exact => ball,
+			_ => Ball::enclosing_points(&mut self.points),
+		}
+	}
+}
+
+impl<T: RealField, D: DimNameAdd<U1>> Default for MinBall<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: RealField, D: DimNameAdd<U1>> Extend<OPoint<T, D>> for MinBall<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	fn extend<I: IntoIterator<Item = OPoint<T, D>>>(&mut self, points: I) {
+		for point in points {
+			self.push(point);
+		}
+	}
+}
+
+impl<T: RealField, D: DimNameAdd<U1>> FromIterator<OPoint<T, D>> for MinBall<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	fn from_iter<I: IntoIterator<Item = OPoint<T, D>>>(points: I) -> Self {
+		let mut min_ball = Self::new();
+		min_ball.extend(points);
+		min_ball
+	}
+}
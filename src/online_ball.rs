@@ -0,0 +1,79 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::Ball;
+use nalgebra::{base::allocator::Allocator, DefaultAllocator, DimName, OPoint, RealField};
+
+/// Approximate minimum ball of streamed points with constant memory.
+///
+/// Implements the streaming algorithm of Zarrabi-Zadeh and Chan, *A Simple Streaming Algorithm
+/// for Minimum Enclosing Balls* (CCCG 2006), which only maintains the current ball. Each point
+/// outside of it is enclosed by replacing it with the minimum ball enclosing it and the point,
+/// see [`Ball::expand_to_contain()`]. This takes *O*(*n*) time and memory per point for *n*
+/// dimensions. The radius is guaranteed to be at most `3 / 2` of the radius of the minimum ball of
+/// all points streamed so far whatever their order.
+///
+/// Unlike `MinBall` of the `std` feature, points are not buffered, so the exact minimum ball
+/// cannot be computed afterwards.
+#[derive(Debug, Clone)]
+pub struct OnlineBall<T: RealField, D: DimName>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	ball: Option<Ball<T, D>>,
+}
+
+impl<T: RealField, D: DimName> OnlineBall<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	/// New online ball without points.
+	#[must_use]
+	#[inline]
+	pub const fn new() -> Self {
+		Self { ball: None }
+	}
+	/// Updates ball to enclose `point`.
+	///
+	/// The first point initializes a ball of zero radius.
+	pub fn update(&mut self, point: &OPoint<T, D>) {
+		match &mut self.ball {
+			Some(ball) => ball.expand_to_contain(point),
+			None => {
+				self.ball = Some(Ball {
+					center: point.clone(),
+					radius_squared: T::zero(),
+				});
+			}
+		}
+	}
+	/// Returns ball enclosing all points streamed so far or `None` if there are none.
+	#[must_use]
+	#[inline]
+	pub const fn ball(&self) -> Option<&Ball<T, D>> {
+		self.ball.as_ref()
+	}
+}
+
+impl<T: RealField, D: DimName> Default for OnlineBall<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<'a, T: RealField, D: DimName> Extend<&'a OPoint<T, D>> for OnlineBall<T, D>
+where
+	DefaultAllocator: Allocator<T, D>,
+{
+	fn extend<I: IntoIterator<Item = &'a OPoint<T, D>>>(&mut self, points: I) {
+		for point in points {
+			self.update(point);
+		}
+	}
+}
@@ -4,11 +4,22 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::mem::take;
+use super::CapacityError;
+use core::{mem::take, slice::Iter};
 use nalgebra::{base::allocator::Allocator, DefaultAllocator, DimName, OVector};
 
 /// Owned vector of item `T` and capacity `D`.
-#[doc(hidden)]
+///
+/// Fixed-capacity vector stored in an [`OVector`] of dimension `D`, so it is stack-allocated for
+/// dimensions known at compile time, e.g., the `D + 1` bounds of [`Enclosing`] implementations.
+///
+/// # Invariants
+///
+///   * The length never exceeds the capacity `D`, pushing onto a full vector panics.
+///   * Items beyond the length are `T::default()`, i.e., popped and cleared items are replaced by
+///     their default, so they are dropped and do not leak resources of `T`.
+///
+/// [`Enclosing`]: crate::Enclosing
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OVec<T: Default, D: DimName>
 where
@@ -60,6 +71,24 @@ where
 	pub fn as_slice(&self) -> &[T] {
 		&self.data.as_slice()[..self.len()]
 	}
+	/// Mutable slice of items.
+	#[must_use]
+	#[inline]
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		let len = self.len();
+		&mut self.data.as_mut_slice()[..len]
+	}
+	/// Iterator over items.
+	#[inline]
+	pub fn iter(&self) -> Iter<'_, T> {
+		self.as_slice().iter()
+	}
+	/// Item at `index` or `None` if out of bounds.
+	#[must_use]
+	#[inline]
+	pub fn get(&self, index: usize) -> Option<&T> {
+		self.as_slice().get(index)
+	}
 	/// Adds `item`.
 	///
 	/// # Panics
@@ -67,6 +96,7 @@ where
 	/// Panics if [`Self::is_full()`].
 	#[inline]
 	pub fn push(&mut self, item: T) {
+		assert!(!self.is_full(), "insufficient capacity");
 		self.data[self.size] = item;
 		self.size += 1;
 	}
@@ -82,6 +112,14 @@ where
 			Some(take(&mut self.data[self.size]))
 		}
 	}
+	/// Removes all items.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.as_mut_slice()
+			.iter_mut()
+			.for_each(|item| drop(take(item)));
+		self.size = 0;
+	}
 }
 
 impl<T: Default, D: DimName> Default for OVec<T, D>
@@ -96,3 +134,52 @@ where
 		}
 	}
 }
+
+impl<T: Default, D: DimName> FromIterator<T> for OVec<T, D>
+where
+	OVector<T, D>: Default,
+	DefaultAllocator: Allocator<T, D>,
+{
+	/// Collects `items`.
+	///
+	/// # Panics
+	///
+	/// Panics if there are more `items` than the capacity `D`.
+	fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+		let mut vec = Self::new();
+		for item in items {
+			vec.push(item);
+		}
+		vec
+	}
+}
+
+impl<T: Default + Clone, D: DimName> TryFrom<&[T]> for OVec<T, D>
+where
+	OVector<T, D>: Default,
+	DefaultAllocator: Allocator<T, D>,
+{
+	type Error = CapacityError;
+
+	/// Clones `items` or returns an error if there are more than the capacity `D`.
+	fn try_from(items: &[T]) -> Result<Self, Self::Error> {
+		if items.len() > D::USIZE {
+			return Err(CapacityError);
+		}
+		Ok(items.iter().cloned().collect())
+	}
+}
+
+impl<'a, T: Default, D: DimName> IntoIterator for &'a OVec<T, D>
+where
+	OVector<T, D>: Default,
+	DefaultAllocator: Allocator<T, D>,
+{
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
@@ -0,0 +1,88 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Ball2, Ball3};
+use nalgebra::{Isometry2, Isometry3, Translation2, Translation3};
+
+impl From<Ball3<f32>> for parry3d::bounding_volume::BoundingSphere {
+	#[inline]
+	fn from(ball: Ball3<f32>) -> Self {
+		Self::new(ball.center, ball.radius())
+	}
+}
+
+impl From<parry3d::bounding_volume::BoundingSphere> for Ball3<f32> {
+	#[inline]
+	fn from(sphere: parry3d::bounding_volume::BoundingSphere) -> Self {
+		Self::new(sphere.center, sphere.radius)
+	}
+}
+
+/// Drops the center, see [`Ball3::to_parry_shape()`] to keep it as translation.
+impl From<Ball3<f32>> for parry3d::shape::Ball {
+	#[inline]
+	fn from(ball: Ball3<f32>) -> Self {
+		Self::new(ball.radius())
+	}
+}
+
+impl From<Ball2<f32>> for parry2d::bounding_volume::BoundingSphere {
+	#[inline]
+	fn from(ball: Ball2<f32>) -> Self {
+		Self::new(ball.center, ball.radius())
+	}
+}
+
+impl From<parry2d::bounding_volume::BoundingSphere> for Ball2<f32> {
+	#[inline]
+	fn from(sphere: parry2d::bounding_volume::BoundingSphere) -> Self {
+		Self::new(sphere.center, sphere.radius)
+	}
+}
+
+/// Drops the center, see [`Ball2::to_parry_shape()`] to keep it as translation.
+impl From<Ball2<f32>> for parry2d::shape::Ball {
+	#[inline]
+	fn from(ball: Ball2<f32>) -> Self {
+		Self::new(ball.radius())
+	}
+}
+
+impl Ball3<f32> {
+	/// Returns `parry3d` ball shape and its position translated by the center.
+	///
+	/// Single-precision only as is `parry3d`, its `f64` counterpart `parry3d-f64` is not supported.
+	#[must_use]
+	pub fn to_parry_shape(&self) -> (Isometry3<f32>, parry3d::shape::Ball) {
+		let position = Translation3::from(self.center.coords).into();
+		(position, parry3d::shape::Ball::new(self.radius()))
+	}
+	/// Ball of `parry3d` ball `shape` at `position`.
+	///
+	/// Inverse of [`Self::to_parry_shape()`] as the rotation of `position` is irrelevant.
+	#[must_use]
+	pub fn from_parry_shape(position: &Isometry3<f32>, shape: &parry3d::shape::Ball) -> Self {
+		Self::new(position.translation.vector.into(), shape.radius)
+	}
+}
+
+impl Ball2<f32> {
+	/// Returns `parry2d` ball shape and its position translated by the center.
+	///
+	/// Single-precision only as is `parry2d`, its `f64` counterpart `parry2d-f64` is not supported.
+	#[must_use]
+	pub fn to_parry_shape(&self) -> (Isometry2<f32>, parry2d::shape::Ball) {
+		let position = Translation2::from(self.center.coords).into();
+		(position, parry2d::shape::Ball::new(self.radius()))
+	}
+	/// Ball of `parry2d` ball `shape` at `position`.
+	///
+	/// Inverse of [`Self::to_parry_shape()`] as the rotation of `position` is irrelevant.
+	#[must_use]
+	pub fn from_parry_shape(position: &Isometry2<f32>, shape: &parry2d::shape::Ball) -> Self {
+		Self::new(position.translation.vector.into(), shape.radius)
+	}
+}
@@ -0,0 +1,19 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+/// Statistics of [`Enclosing::enclosing_points_with_stats()`].
+///
+/// [`Enclosing::enclosing_points_with_stats()`]: crate::Enclosing::enclosing_points_with_stats
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EnclosingStats {
+	/// Number of invocations of [`Enclosing::with_bounds()`](crate::Enclosing::with_bounds).
+	pub with_bounds_calls: usize,
+	/// Maximum recursion depth reached.
+	pub max_depth: usize,
+	/// Whether the remaining stack space fell below the red zone, so new stack space has been
+	/// allocated on the heap.
+	pub red_zone_entered: bool,
+}
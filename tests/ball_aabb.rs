@@ -0,0 +1,35 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+#[test]
+fn aabb_of_2_ball() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let ball = Ball::new(Point2::from(offset), 2.0);
+	let (min, max) = ball.aabb();
+	assert_eq!(min, Point2::new(-2.0, -2.0) + offset);
+	assert_eq!(max, Point2::new(2.0, 2.0) + offset);
+	// Inside box and ball.
+	assert!(ball.aabb_contains_point(&(Point2::new(1.0, -1.0) + offset)));
+	// Inside box but outside ball.
+	let corner = Point2::new(1.9, 1.9) + offset;
+	assert!(ball.aabb_contains_point(&corner) && !ball.contains(&corner));
+	// Outside box.
+	assert!(!ball.aabb_contains_point(&(Point2::new(2.1, 0.0) + offset)));
+}
+
+#[test]
+fn aabb_of_3_ball() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(Point3::from(offset), 0.5);
+	let (min, max) = ball.aabb();
+	assert_eq!(min, Point3::new(-3.5, 6.5, 4.3));
+	assert_eq!(max, Point3::new(-2.5, 7.5, 5.3));
+	assert!(ball.aabb_contains_point(&min) && ball.aabb_contains_point(&max));
+	assert!(!ball.aabb_contains_point(&(Point3::new(0.0, 0.0, -0.6) + offset)));
+}
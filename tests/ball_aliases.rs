@@ -0,0 +1,20 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Ball2, Ball3, Ball4, Enclosing};
+use nalgebra::{Point2, Point3, Point4};
+
+#[test]
+fn aliased_balls_with_bounds() {
+	let ball = Ball2::with_bounds(&[Point2::<f64>::new(-1.0, 0.0), Point2::new(1.0, 0.0)]).unwrap();
+	assert_eq!(ball.radius_squared, 1.0);
+	let ball: Ball<f64, _> = Ball3::new(Point3::origin(), 2.0);
+	assert_eq!(ball.radius_squared, 4.0);
+	let ball = Ball4::<f64>::with_bounds(&[Point4::origin()]).unwrap();
+	assert_eq!(ball.radius_squared, 0.0);
+}
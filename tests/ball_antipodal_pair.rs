@@ -0,0 +1,28 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn antipodal_pair_of_3_ball_diameter() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let a = Point3::new(-2.0, 1.0, 0.5) + offset;
+	let b = Point3::new(2.0, -1.0, -0.5) + offset;
+	let mut points = [(0.5, 0.3, -0.2), (-0.4, 0.1, 0.6), (0.0, -0.7, 0.0)]
+		.map(|(x, y, z)| Point3::new(x, y, z) + offset)
+		.into_iter()
+		.chain([a, b])
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points(&mut points);
+	let (p, q) = ball.antipodal_pair(&points).unwrap();
+	assert!((*p == a && *q == b) || (*p == b && *q == a));
+	assert!(ball.antipodal_pair(&[a]).is_none());
+	assert!(ball.antipodal_pair(&[a, Point3::from(offset)]).is_none());
+}
@@ -0,0 +1,35 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "approx")]
+
+use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_relative_eq_expected() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = [
+		(1.0, 0.0, 0.0),
+		(-1.0, 0.0, 0.0),
+		(0.0, 1.0, 0.0),
+		(0.0, -1.0, 0.0),
+		(0.0, 0.0, 1.0),
+		(0.0, 0.0, -1.0),
+		(0.5, 0.5, 0.0),
+	]
+	.map(|(x, y, z)| Point3::new(x, y, z) * 1.3 + offset)
+	.into_iter()
+	.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points(&mut points);
+	let expected = Ball::new(offset.into(), 1.3);
+	assert_relative_eq!(ball, expected, epsilon = 1e-6);
+	assert_abs_diff_eq!(ball, expected, epsilon = 1e-6);
+	assert_relative_ne!(ball, expected.translated(&Vector3::x()), epsilon = 1e-6);
+	assert_relative_ne!(ball, expected.scaled(1.1), epsilon = 1e-6);
+}
@@ -0,0 +1,59 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point, Point3, SVector, Vector3};
+use rand::{rngs::StdRng, SeedableRng};
+use rand_distr::StandardNormal;
+use std::collections::VecDeque;
+
+#[test]
+fn approximate_3_ball_within_epsilon_of_minimum_3_ball() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<Vec<_>>();
+	for epsilon in [0.5, 0.1, 0.02] {
+		let approximate = Ball::approximate_enclosing_points(&points, epsilon, usize::MAX);
+		assert!(approximate.contains_all(&points));
+		let minimum = Ball::enclosing_points(&mut points.iter().copied().collect::<VecDeque<_>>());
+		assert!(approximate.radius() <= minimum.radius() * (1.0 + epsilon));
+		assert!(approximate.radius() >= minimum.radius() * (1.0 - f64::EPSILON.sqrt()));
+	}
+	// Capping iterations at zero returns ball around first point.
+	let approximate = Ball::approximate_enclosing_points(&points, 0.02, 0);
+	assert_eq!(approximate.center, points[0]);
+}
+
+#[test]
+fn approximate_100_ball_enclosing_gaussian_blob_converges() {
+	let mut rng = StdRng::seed_from_u64(42);
+	let points = (0..1_000)
+		.map(|_point| {
+			Point::from(SVector::<f64, 100>::from_distribution(
+				&StandardNormal,
+				&mut rng,
+			))
+		})
+		.collect::<Vec<_>>();
+	// Half the diameter is a lower bound of the minimum radius.
+	let half_diameter = points
+		.iter()
+		.flat_map(|a| points.iter().map(move |b| (a - b).norm() / 2.0))
+		.fold(0.0, f64::max);
+	let mut radii = Vec::new();
+	for epsilon in [0.4, 0.2, 0.1, 0.05] {
+		let approximate = Ball::approximate_enclosing_points(&points, epsilon, usize::MAX);
+		assert!(approximate.contains_all(&points));
+		assert!(approximate.radius() >= half_diameter);
+		radii.push((epsilon, approximate.radius()));
+	}
+	// Coarser approximations are within their epsilon of the finest one.
+	let (_epsilon, finest) = radii[radii.len() - 1];
+	for (epsilon, radius) in radii {
+		assert!(radius <= finest * (1.0 + epsilon));
+	}
+}
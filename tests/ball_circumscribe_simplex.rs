@@ -0,0 +1,46 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball2, Ball3, Location};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+#[test]
+fn circumscribed_2_ball_of_triangle() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let vertices = [(1.0, 0.0), (-1.0, 0.0), (0.0, 1.0)].map(|(x, y)| Point2::new(x, y) + offset);
+	let ball = Ball2::circumscribe_simplex(&vertices).unwrap();
+	assert!((ball.center - Point2::from(offset)).norm() < f64::EPSILON.sqrt());
+	assert!(vertices
+		.iter()
+		.all(|vertex| ball.locate(vertex) == Location::Surface));
+	let collinear = [(1.0, 0.0), (-1.0, 0.0), (0.0, 0.0)].map(|(x, y)| Point2::new(x, y) + offset);
+	assert!(Ball2::circumscribe_simplex(&collinear).is_none());
+}
+
+#[test]
+fn circumscribed_3_ball_of_tetrahedron() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let vertices = [
+		(1.0, 0.0, 0.0),
+		(-1.0, 0.0, 0.0),
+		(0.0, 1.0, 0.0),
+		(0.0, 0.0, -1.0),
+	]
+	.map(|(x, y, z)| Point3::new(x, y, z) + offset);
+	let ball = Ball3::circumscribe_simplex(&vertices).unwrap();
+	assert!((ball.center - Point3::from(offset)).norm() < f64::EPSILON.sqrt());
+	assert!(vertices
+		.iter()
+		.all(|vertex| ball.locate(vertex) == Location::Surface));
+	let coplanar = [
+		(1.0, 0.0, 0.0),
+		(-1.0, 0.0, 0.0),
+		(0.0, 1.0, 0.0),
+		(0.0, -1.0, 0.0),
+	]
+	.map(|(x, y, z)| Point3::new(x, y, z) + offset);
+	assert!(Ball3::circumscribe_simplex(&coplanar).is_none());
+}
@@ -0,0 +1,38 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn clearances_of_points_to_3_ball() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 9.0,
+	};
+	let points = [
+		Point3::new(0.0, 0.0, 0.0),
+		Point3::new(1.0, 2.0, 0.0),
+		Point3::new(0.0, 0.0, -3.0),
+		Point3::new(2.0, 4.0, 4.0),
+	]
+	.map(|point| point + offset);
+	let clearances = ball.clearances(&points);
+	// Interior, interior, surface, and exterior.
+	assert_eq!(clearances.len(), 4);
+	assert_eq!(clearances[0], 3.0);
+	assert!((clearances[1] - (3.0 - 5f64.sqrt())).abs() <= 1e-12);
+	assert!(clearances[1] > 0.0);
+	assert!(clearances[2].abs() <= 1e-12);
+	assert!((clearances[3] + 3.0).abs() <= 1e-12);
+	for (point, clearance) in points.iter().zip(clearances) {
+		assert_eq!(-ball.signed_distance(point), clearance);
+	}
+}
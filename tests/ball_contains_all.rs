@@ -0,0 +1,30 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Vector2};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_2_ball_contains_all_points() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let mut points = [
+		(-1.0, -1.0),
+		(-1.0, 1.0),
+		(1.0, -1.0),
+		(1.0, 1.0),
+		(0.5, 0.0),
+	]
+	.map(|(x, y)| Point2::new(x, y) + offset)
+	.into_iter()
+	.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points(&mut points);
+	assert!(ball.contains_all(&points));
+	assert!(ball.contains_all(&[]));
+	assert!(!ball.contains_all(&[Point2::new(2.0, 0.0) + offset]));
+	assert_eq!(ball.count_on_surface(&points, f64::EPSILON.sqrt()), 4);
+	assert_eq!(ball.count_on_surface(&points, 1.0), 5);
+}
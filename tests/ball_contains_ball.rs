@@ -0,0 +1,69 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+#[test]
+fn outer_2_ball_contains_nested_2_balls() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let outer = Ball::new(offset.into(), 3.0);
+	let inner = Ball::new(Point2::new(1.0, 0.0) + offset, 1.0);
+	let innermost = Ball::new(Point2::new(1.5, 0.0) + offset, 0.25);
+	assert!(outer.contains_ball(&inner));
+	assert!(inner.contains_ball(&innermost));
+	assert!(outer.contains_ball(&innermost));
+	assert!(!inner.contains_ball(&outer));
+	// Equal balls contain each other.
+	assert!(outer.contains_ball(&outer));
+	// Touching from inside.
+	let touching = Ball::new(Point2::new(2.0, 0.0) + offset, 1.0);
+	assert!(outer.contains_ball(&touching));
+	// Zero radius reduces to point containment.
+	for point in [Point2::new(3.0, 0.0), Point2::new(3.1, 0.0)] {
+		let point = point + offset;
+		assert_eq!(
+			outer.contains_ball(&Ball::new(point, 0.0)),
+			outer.contains(&point)
+		);
+	}
+}
+
+#[test]
+fn outer_3_ball_barely_contains_3_ball() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let outer = Ball::new(offset.into(), 3.0);
+	let epsilon = f64::EPSILON.sqrt();
+	// Within relative tolerance of radius squared.
+	let inside = Ball::new(
+		Point3::new(0.0, 0.0, epsilon.mul_add(0.25, 2.0)) + offset,
+		1.0,
+	);
+	assert!(outer.contains_ball(&inside));
+	// Beyond relative tolerance of radius squared.
+	let outside = Ball::new(
+		Point3::new(0.0, 0.0, epsilon.mul_add(4.0, 2.0)) + offset,
+		1.0,
+	);
+	assert!(!outer.contains_ball(&outside));
+}
+
+#[test]
+fn same_radius_3_balls_contain_each_other_only_if_equal() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(offset.into(), 3.0);
+	let same = Ball::new(offset.into(), 3.0);
+	assert!(ball.contains_ball(&same));
+	assert!(same.contains_ball(&ball));
+	// Same radius but offset center beyond relative tolerance.
+	let shifted = Ball::new(Point3::new(0.0, 0.0, 1e-6) + offset, 3.0);
+	assert!(!ball.contains_ball(&shifted));
+	assert!(!shifted.contains_ball(&ball));
+	// Larger radius beyond relative tolerance.
+	let larger = Ball::new(ball.center, 3.0 + 1e-6);
+	assert!(!ball.contains_ball(&larger));
+	assert!(larger.contains_ball(&ball));
+}
@@ -0,0 +1,39 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Ellipsoid, Enclosing};
+use nalgebra::{Matrix2, Point2, Rotation2, Vector2, U2};
+
+/// Ellipse centered at `center` with semi-axes `a` and `b` rotated by `angle`.
+fn ellipse(center: Point2<f64>, a: f64, b: f64, angle: f64) -> Ellipsoid<f64, U2> {
+	let rotation = Rotation2::new(angle).into_inner();
+	let shape =
+		rotation * Matrix2::new(1.0 / (a * a), 0.0, 0.0, 1.0 / (b * b)) * rotation.transpose();
+	Ellipsoid { center, shape }
+}
+
+#[test]
+fn ball_containing_ellipse() {
+	let ball = Ball::new(Point2::new(1.0, -2.0), 3.0);
+	let inside = ellipse(Point2::new(1.5, -2.0), 2.0, 1.0, 0.7);
+	assert!(ball.contains_ellipsoid(&inside));
+}
+
+#[test]
+fn ball_not_containing_ellipse_with_major_axis_poking_out() {
+	let ball = Ball::new(Point2::new(1.0, -2.0), 3.0);
+	let poking = ellipse(Point2::new(1.0, -2.0), 3.5, 0.5, 0.0);
+	assert!(!ball.contains_ellipsoid(&poking));
+	// Conservative as bounding ball of ellipse inside of ball pokes out.
+	let center = Point2::new(2.5, -2.0);
+	let rotation = Rotation2::new(1.5);
+	let tilted = ellipse(center, 1.6, 0.5, 1.5);
+	assert!((0..360).all(|degree| {
+		let angle = f64::from(degree).to_radians();
+		ball.contains(&(center + rotation * Vector2::new(1.6 * angle.cos(), 0.5 * angle.sin())))
+	}));
+	assert!(!ball.contains_ellipsoid(&tilted));
+}
@@ -0,0 +1,40 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+/// Returns corners of frustum looking along negative z-axis from `eye`.
+fn frustum(eye: &Point3<f64>, near: f64, far: f64, half_width: f64) -> [Point3<f64>; 8] {
+	let (near_half, far_half) = (half_width * near, half_width * far);
+	[
+		(near, near_half, near_half),
+		(near, -near_half, near_half),
+		(near, near_half, -near_half),
+		(near, -near_half, -near_half),
+		(far, far_half, far_half),
+		(far, -far_half, far_half),
+		(far, far_half, -far_half),
+		(far, -far_half, -far_half),
+	]
+	.map(|(depth, x, y)| eye + Vector3::new(x, y, -depth))
+}
+
+#[test]
+fn frustum_contained_by_3_ball() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 9.0,
+	};
+	let eye = Point3::new(0.0, 0.0, 1.0) + offset;
+	// Fully inside.
+	assert!(ball.contains_frustum(&frustum(&eye, 0.1, 2.0, 0.5)));
+	// Partially outside with far plane behind ball.
+	assert!(!ball.contains_frustum(&frustum(&eye, 0.1, 5.0, 0.5)));
+	// Partially outside with wide field of view.
+	assert!(!ball.contains_frustum(&frustum(&eye, 0.1, 2.0, 2.0)));
+}
@@ -0,0 +1,36 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn grid_block_inside_3_ball() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 9.0,
+	};
+	let origin = Point3::new(-1.0, -1.0, -1.0) + offset;
+	assert!(ball.contains_grid_block(&origin, 0.5, &[4, 4, 4]));
+	assert!(ball.contains_grid_block(&origin, 0.25, &[8, 4, 2]));
+}
+
+#[test]
+fn grid_block_poking_out_of_3_ball() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 9.0,
+	};
+	let origin = Point3::new(-1.0, -1.0, -1.0) + offset;
+	// Corner at distance of √12 > 3 from center.
+	assert!(!ball.contains_grid_block(&origin, 0.5, &[6, 6, 6]));
+	// Corner at distance of √11 > 3 from center.
+	assert!(!ball.contains_grid_block(&origin, 0.5, &[6, 6, 5]));
+	// Corner at distance of √8 < 3 from center.
+	assert!(ball.contains_grid_block(&origin, 0.5, &[6, 4, 2]));
+}
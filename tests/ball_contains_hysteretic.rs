@@ -0,0 +1,36 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn hysteretic_2_ball_contains_points() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 4.0,
+	};
+	let (enter, exit) = (0.25, 0.5);
+	// Between both thresholds, previous state is retained.
+	for point in [
+		Point2::new(1.9, 0.0),
+		Point2::new(0.0, 2.0),
+		Point2::new(-2.4, 0.0),
+	] {
+		let point = point + offset;
+		assert!(ball.contains_hysteretic(&point, enter, exit, true));
+		assert!(!ball.contains_hysteretic(&point, enter, exit, false));
+	}
+	// Beyond enter threshold, point is contained.
+	let point = Point2::new(0.0, 1.5) + offset;
+	assert!(ball.contains_hysteretic(&point, enter, exit, false));
+	assert!(ball.contains_hysteretic(&point, enter, exit, true));
+	// Beyond exit threshold, point is not contained.
+	let point = Point2::new(0.0, -2.6) + offset;
+	assert!(!ball.contains_hysteretic(&point, enter, exit, false));
+	assert!(!ball.contains_hysteretic(&point, enter, exit, true));
+}
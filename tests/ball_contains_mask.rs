@@ -0,0 +1,30 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::Ball;
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn mask_of_points_contained_by_2_ball() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 4.0,
+	};
+	let points = [
+		Point2::new(0.0, 0.0),
+		Point2::new(3.0, 0.0),
+		Point2::new(0.0, -2.0),
+		Point2::new(1.5, 1.5),
+		Point2::new(1.0, -1.0),
+	]
+	.map(|point| point + offset);
+	let mask = ball.contains_mask(&points);
+	assert_eq!(mask, [true, false, true, false, true]);
+	assert!(ball.contains_mask([]).is_empty());
+}
@@ -0,0 +1,28 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Point2, Rotation2, Vector2};
+use std::f64::consts::FRAC_PI_2;
+
+#[test]
+fn contains_point_of_rotated_and_translated_frame() {
+	let ball = Ball {
+		center: Point2::new(4.0, 0.0),
+		radius_squared: 1.0,
+	};
+	// Rotates by a quarter turn and translates by `(4, -2)`.
+	let rotation = Rotation2::new(FRAC_PI_2).into_inner();
+	let translation = Vector2::new(4.0, -2.0);
+	// Maps to `(4, 0)` at center.
+	assert!(ball.contains_transformed(&Point2::new(2.0, 0.0), &rotation, &translation));
+	// Maps to `(3.5, 0.5)` inside.
+	assert!(ball.contains_transformed(&Point2::new(2.5, 0.5), &rotation, &translation));
+	// Maps to `(2, 0)` outside.
+	assert!(!ball.contains_transformed(&Point2::new(2.0, 2.0), &rotation, &translation));
+	// Untransformed point would be inside.
+	assert!(!ball.contains_transformed(&Point2::new(4.0, 0.0), &rotation, &translation));
+}
@@ -0,0 +1,61 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{distance, Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn deduplicate_near_points_in_3_cube() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+		.map(|point| point * 3.0 + offset)
+		.collect::<Vec<_>>();
+	let near_duplicates = points
+		.iter()
+		.step_by(10)
+		.map(|point| point + (Vector3::new_random() - Vector3::from_element(0.5)) * 1e-9);
+	let mut deduplicated = points
+		.iter()
+		.copied()
+		.chain(near_duplicates)
+		.collect::<VecDeque<_>>();
+	let mut original = deduplicated.clone();
+	assert_eq!(Ball::deduplicate_near(&mut deduplicated, 1e-6), 100);
+	assert!(deduplicated.iter().eq(points.iter()));
+	assert_eq!(Ball::deduplicate_near(&mut deduplicated, 1e-6), 0);
+	let Ball {
+		center,
+		radius_squared,
+	} = Ball::enclosing_points(&mut deduplicated);
+	let ball = Ball::enclosing_points(&mut original);
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	assert!((ball.radius_squared / radius_squared - 1.0).abs() <= epsilon);
+	assert!(distance(&ball.center, &center) <= epsilon);
+}
+
+#[test]
+fn deduplicate_near_points_straddling_zero() {
+	let mut points = VecDeque::from([
+		Point3::new(-0.0, 1.0, 2.0),
+		Point3::new(0.0, 1.0, 2.0),
+		Point3::new(-1e-9, 1.0, 2.0),
+		Point3::new(1e-9, 1.0, 2.0),
+	]);
+	assert_eq!(Ball::deduplicate_near(&mut points, 1e-6), 3);
+	assert_eq!(points.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "cell index out of range")]
+fn deduplicate_near_infinite_point() {
+	let mut points = VecDeque::from([Point3::new(f64::INFINITY, 1.0, 2.0)]);
+	let _removed = Ball::deduplicate_near(&mut points, 1e-6);
+}
@@ -0,0 +1,48 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing, Intersection};
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn empty_3_ball() {
+	let empty = Ball::<f64, _>::empty();
+	assert!(empty.is_empty());
+	assert_eq!(empty.center, Point3::origin());
+	assert!(!empty.contains(&Point3::origin()));
+	assert!(!empty.contains(&Point3::new(1.0, 2.0, 3.0)));
+	let ball = Ball::new(Point3::new(0.0, 0.0, 0.0), 2.0);
+	assert!(!ball.is_empty());
+	assert!(!empty.intersects(&ball));
+	assert!(!ball.intersects(&empty));
+	assert!(!empty.intersects(&empty));
+	assert_eq!(empty.intersection_kind(&ball), Intersection::Disjoint);
+	assert!(ball.contains_ball(&empty));
+	assert!(empty.contains_ball(&empty));
+	assert!(!empty.contains_ball(&ball));
+	assert_eq!(empty.merged(&ball), ball);
+	assert_eq!(ball.merged(&empty), ball);
+	assert!(empty.merged(&empty).is_empty());
+}
+
+#[test]
+fn empty_3_ball_expanded_to_contain_points() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<Vec<_>>();
+	let mut ball = Ball::empty();
+	ball.expand_to_contain(&points[0]);
+	assert_eq!(ball, Ball::new(points[0], 0.0));
+	let ball = points.iter().fold(Ball::empty(), |mut ball, point| {
+		ball.expand_to_contain(point);
+		ball
+	});
+	assert!(!ball.is_empty());
+	assert!(points.iter().all(|point| ball.contains(point)));
+}
@@ -0,0 +1,96 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing, Intersection};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_2_ball_enclosing_3_mutually_tangent_2_balls() {
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	// Equal 2-balls tangent to each other with enclosing 2-ball tangent to all of them.
+	let mut balls = [0.0, 120.0, 240.0]
+		.map(|angle: f64| {
+			let (sin, cos) = angle.to_radians().sin_cos();
+			Ball::new(Point2::new(cos, sin) * 2.0 / 3f64.sqrt() + offset, 1.0)
+		})
+		.into_iter()
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_balls(&mut balls);
+	assert_eq!(balls.len(), 3);
+	assert!((ball.center - Point2::from(offset)).norm() <= epsilon);
+	assert!((ball.radius() - (2.0 / 3f64.sqrt() + 1.0)).abs() <= epsilon);
+	// Different 2-balls tangent to each other with enclosing 2-ball tangent to two of them.
+	let mut balls = [((0.0, 0.0), 1.0), ((3.0, 0.0), 2.0), ((0.0, 4.0), 3.0)]
+		.map(|((x, y), radius)| Ball::new(Point2::new(x, y) + offset, radius))
+		.into_iter()
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_balls(&mut balls);
+	assert!((ball.center - (Point2::new(1.2, 2.4) + offset)).norm() <= epsilon);
+	assert!((ball.radius() - 5.0).abs() <= epsilon);
+	for other in &balls {
+		let far_side = (other.center - ball.center).norm() + other.radius();
+		assert!(far_side <= ball.radius() * (1.0 + epsilon));
+	}
+	// Not tangent to smallest one.
+	let smallest = Ball::new(Point2::from(offset), 1.0);
+	assert_eq!(ball.intersection_kind(&smallest), Intersection::Contained);
+}
+
+#[test]
+fn minimum_2_ball_enclosing_contained_2_balls() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let outer = Ball::new(Point2::new(0.5, 0.0) + offset, 4.0);
+	let mut balls = [((0.0, 0.0), 1.0), ((2.0, 1.0), 1.5), ((-1.0, -1.0), 0.5)]
+		.map(|((x, y), radius)| Ball::new(Point2::new(x, y) + offset, radius))
+		.into_iter()
+		.chain([outer])
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_balls(&mut balls);
+	assert!((ball.center - outer.center).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius() - outer.radius()).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+fn minimum_3_ball_enclosing_3_balls_of_zero_radius() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<VecDeque<_>>();
+	let mut balls = points
+		.iter()
+		.map(|&point| Ball::new(point, 0.0))
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_balls(&mut balls);
+	let expected = Ball::enclosing_points(&mut points);
+	assert!((ball.center - expected.center).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius() - expected.radius()).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+fn minimum_3_ball_enclosing_random_3_balls() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	for _sample in 0..100 {
+		let mut balls = (0..100)
+			.map(|_ball| {
+				let center = Vector3::new_random() - Vector3::from_element(0.5) + offset;
+				Ball::new(Point3::from(center), rand::random::<f64>() * 0.25)
+			})
+			.collect::<VecDeque<_>>();
+		let ball = Ball::enclosing_balls(&mut balls);
+		let merged = balls
+			.iter()
+			.fold(balls[0], |merged, other| merged.merged(other));
+		assert!(ball.radius() <= merged.radius() * (1.0 + f64::EPSILON.sqrt()));
+		for other in &balls {
+			let far_side = (other.center - ball.center).norm() + other.radius();
+			assert!(far_side <= ball.radius() * (1.0 + f64::EPSILON.sqrt()));
+		}
+	}
+}
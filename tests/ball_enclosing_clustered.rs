@@ -0,0 +1,62 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn minimum_2_balls_enclosing_2_clusters() {
+	let left = Vector2::new(-30.0, 7.0);
+	let right = Vector2::new(30.0, -7.0);
+	let square = [
+		Point2::new(1.0, 1.0),
+		Point2::new(1.0, -1.0),
+		Point2::new(-1.0, 1.0),
+		Point2::new(-1.0, -1.0),
+		Point2::new(0.5, 0.0),
+	];
+	let line = [
+		Point2::new(-3.0, 0.0),
+		Point2::new(3.0, 0.0),
+		Point2::new(0.0, 1.0),
+	];
+	let mut points = Vec::new();
+	let mut labels = Vec::new();
+	for point in square {
+		points.push(point + left);
+		labels.push(4);
+	}
+	for point in line {
+		points.push(point + right);
+		labels.push(2);
+	}
+	points.rotate_left(3);
+	labels.rotate_left(3);
+	let balls = Ball::enclosing_clustered(&points, &labels);
+	assert_eq!(balls.len(), 2);
+	let Ball {
+		center,
+		radius_squared,
+	} = balls[0];
+	assert_eq!(center, right.into());
+	assert_eq!(radius_squared, 9.0);
+	let Ball {
+		center,
+		radius_squared,
+	} = balls[1];
+	assert_eq!(center, left.into());
+	assert_eq!(radius_squared, 2.0);
+}
+
+#[test]
+#[should_panic(expected = "mismatching labels")]
+fn minimum_2_balls_enclosing_mislabeled_clusters() {
+	let points = [Point2::new(1.0, 1.0), Point2::new(-1.0, 1.0)];
+	let _balls = Ball::enclosing_clustered(&points, &[0]);
+}
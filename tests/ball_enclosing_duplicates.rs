@@ -0,0 +1,31 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_enclosing_exact_duplicates() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	for _randomize in 0..100 {
+		let distinct = (0..100)
+			.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+			.collect::<Vec<_>>();
+		// 90% of points are exact duplicates of distinct points.
+		let mut points = distinct
+			.iter()
+			.flat_map(|point| [*point; 10])
+			.collect::<VecDeque<_>>();
+		let ball = Ball::enclosing_points(&mut points);
+		assert_eq!(points.len(), 1_000);
+		assert!(points.iter().all(|point| ball.contains(point)));
+		let minimum = Ball::enclosing_points(&mut distinct.into_iter().collect::<VecDeque<_>>());
+		assert!(ball.approx_eq(&minimum, f64::EPSILON.sqrt()));
+	}
+}
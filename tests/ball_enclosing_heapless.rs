@@ -0,0 +1,38 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "heapless")]
+
+use heapless::Deque as HeaplessDeque;
+use miniball::{Ball, Deque, Enclosing};
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn minimum_2_ball_enclosing_2_square_in_heapless_deque() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let mut points = HeaplessDeque::<_, 5>::new();
+	for (x, y) in [
+		(-1.0, -1.0),
+		(-1.0, 1.0),
+		(1.0, -1.0),
+		(1.0, 1.0),
+		(0.5, 0.0),
+	] {
+		Deque::push_back(&mut points, Point2::new(x, y) + offset);
+	}
+	let ball = Ball::enclosing_points(&mut points);
+	assert_eq!(points.len(), 5);
+	assert!((ball.center - Point2::from(offset)).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius_squared - 2.0).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+#[should_panic(expected = "insufficient capacity")]
+fn push_front_to_full_heapless_deque() {
+	let mut points = HeaplessDeque::<_, 1>::new();
+	Deque::push_back(&mut points, 1);
+	Deque::push_front(&mut points, 0);
+}
@@ -0,0 +1,59 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(all(feature = "std", debug_assertions))]
+
+use miniball::{Ball, Deque, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+/// Deque silently dropping every 7th pushed element.
+struct Lossy<T> {
+	deque: VecDeque<T>,
+	pushes: usize,
+}
+
+impl<T> Lossy<T> {
+	fn keep(&mut self) -> bool {
+		self.pushes += 1;
+		self.pushes % 7 != 0
+	}
+}
+
+impl<T> Deque<T> for Lossy<T> {
+	fn len(&self) -> usize {
+		self.deque.len()
+	}
+
+	fn pop_front(&mut self) -> Option<T> {
+		self.deque.pop_front()
+	}
+	fn pop_back(&mut self) -> Option<T> {
+		self.deque.pop_back()
+	}
+
+	fn push_front(&mut self, value: T) {
+		if self.keep() {
+			self.deque.push_front(value);
+		}
+	}
+	fn push_back(&mut self, value: T) {
+		if self.keep() {
+			self.deque.push_back(value);
+		}
+	}
+}
+
+#[test]
+#[should_panic(expected = "deque length not conserved")]
+fn lossy_deque_fails_debug_assertion() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let deque = (0..100)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+		.map(|point| point * 3.0 + offset)
+		.collect::<VecDeque<_>>();
+	let _ball = Ball::enclosing_points(&mut Lossy { deque, pushes: 0 });
+}
@@ -0,0 +1,38 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_enclosing_near_duplicate_co_spherical_points() {
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let radius = 3.0;
+	for _randomize in 0..100 {
+		// Few distinct co-spherical points each repeated many times within epsilon.
+		let mut points = (0..8)
+			.map(|_point| (Vector3::<f64>::new_random() - Vector3::from_element(0.5)).normalize())
+			.flat_map(|direction| {
+				(0..64).map(move |_duplicate| {
+					let jitter = (Vector3::new_random() - Vector3::from_element(0.5)) * epsilon;
+					Point3::from(direction * radius + jitter)
+				})
+			})
+			.map(|point| point + offset)
+			.collect::<VecDeque<_>>();
+		let ball = Ball::enclosing_points(&mut points);
+		// Ensures no point got lost.
+		assert_eq!(points.len(), 8 * 64);
+		// Ensures 3-ball is minimum and encloses all points.
+		assert!(ball.radius_squared.sqrt() <= radius * (1.0 + epsilon));
+		assert!(points.iter().all(|point| ball.contains(point)));
+	}
+}
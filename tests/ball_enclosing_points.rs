@@ -103,6 +103,55 @@ fn minimum_3_ball_enclosing_3_line() {
 	assert_eq!(radius_squared, 9.0);
 }
 
+#[test]
+fn minimum_3_ball_enclosing_3_line_starting_inside() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let a = Point3::new(-1.0, 0.0, 0.0);
+	let b = Point3::new(-0.5, 0.0, 0.0);
+	let c = Point3::new(0.5, 0.0, 0.0);
+	let d = Point3::new(1.0, 0.0, 0.0);
+	// Line grows from the first point in both directions.
+	let Ball {
+		center,
+		radius_squared,
+	} = Ball::enclosing_points(
+		&mut [b, c, a, d]
+			.map(|bound| bound * 3.0)
+			.map(|bound| bound + offset)
+			.into_iter()
+			.collect::<VecDeque<_>>(),
+	);
+	assert_eq!(center, offset.into());
+	assert_eq!(radius_squared, 9.0);
+}
+
+#[test]
+fn minimum_3_ball_enclosing_3_line_of_clustered_points() {
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let direction = Vector3::new(1.0, 2.0, 2.0);
+	for _randomize in 0..100 {
+		// Many points clustered near both ends and the middle of a skew line.
+		let mut points = (0..1_000)
+			.map(|point| {
+				let cluster = f64::from(point % 3) - 1.0;
+				let spread = (rand::random::<f64>() - 0.5) * 1e-6;
+				cluster * (1.0 - spread.abs()) + spread * (1.0 - cluster.abs())
+			})
+			.chain([-1.0, 1.0])
+			.map(|parameter| Point3::from(direction * parameter + offset))
+			.collect::<VecDeque<_>>();
+		let Ball {
+			center,
+			radius_squared,
+		} = Ball::enclosing_points(&mut points);
+		assert_eq!(points.len(), 1_002);
+		assert!((center - Point3::from(offset)).norm() <= epsilon);
+		assert!((radius_squared - 9.0).abs() <= epsilon);
+	}
+}
+
 #[test]
 fn minimum_6_ball_enclosing_6_cube() {
 	for _randomize in 0..100 {
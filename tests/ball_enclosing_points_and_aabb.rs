@@ -0,0 +1,68 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{distance, Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_and_aabb_enclosing_3_cube() {
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+		.map(|point| point * 3.0 + offset)
+		.collect::<VecDeque<_>>();
+	let unpermuted = points.clone();
+	let (ball, (min, max)) = Ball::enclosing_points_and_aabb(&mut points);
+	assert_eq!(points.len(), 1_000);
+	// Ensures AABB bounds all points.
+	assert!(unpermuted
+		.iter()
+		.all(|point| min <= *point && *point <= max));
+	// Ensures AABB is tight by having a point on each of its faces.
+	for axis in 0..3 {
+		assert!(unpermuted.iter().any(|point| point[axis] == min[axis]));
+		assert!(unpermuted.iter().any(|point| point[axis] == max[axis]));
+	}
+	// Ensures all points are enclosed by 3-ball.
+	let radius = ball.radius_squared.sqrt();
+	let all_enclosed = points
+		.iter()
+		.all(|point| distance(point, &ball.center) <= radius + epsilon);
+	assert!(all_enclosed);
+}
+
+#[test]
+fn minimum_3_ball_and_aabb_enclosing_collinear_points() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let direction = Vector3::<f64>::new(1.0, -2.0, 0.5);
+	let mut points = [0.5, -1.5, 2.0, 0.0, -0.5]
+		.into_iter()
+		.map(|step| Point3::from(offset + direction * step))
+		.collect::<VecDeque<_>>();
+	let (ball, (min, max)) = Ball::enclosing_points_and_aabb(&mut points);
+	assert_eq!(points.len(), 5);
+	let (a, b) = (offset + direction * -1.5, offset + direction * 2.0);
+	assert_eq!(min, Point3::from(a.inf(&b)));
+	assert_eq!(max, Point3::from(a.sup(&b)));
+	assert!((ball.radius() / direction.norm() - 1.75).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+fn minimum_3_ball_and_aabb_enclosing_single_point() {
+	let point = Point3::new(-3.0, 7.0, 4.8);
+	let mut points = VecDeque::from([point]);
+	let (ball, (min, max)) = Ball::enclosing_points_and_aabb(&mut points);
+	assert_eq!(points, [point]);
+	assert_eq!((min, max), (point, point));
+	assert_eq!(ball.center, point);
+	assert_eq!(ball.radius_squared, 0.0);
+}
@@ -0,0 +1,40 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{distance, Point2, Vector2};
+use std::{collections::VecDeque, f64::consts::TAU};
+
+#[test]
+fn minimum_2_ball_enclosing_2_sphere_far_from_origin() {
+	let center = Point2::new(1e6, -1e6);
+	let radius = 1e-3;
+	let mut misclassified = 0;
+	for _randomize in 0..100 {
+		// Points on the surface of a tiny 2-sphere far from origin.
+		let points = (0..100)
+			.map(|_point| rand::random::<f64>() * TAU)
+			.map(|angle| center + Vector2::new(angle.cos(), angle.sin()) * radius)
+			.collect::<VecDeque<_>>();
+		// Fixed epsilon misclassifies some surface points.
+		let ball = Ball::enclosing_points(&mut points.clone());
+		if !points.iter().all(|point| ball.contains(point)) {
+			misclassified += 1;
+		}
+		// Adaptive epsilon identifies all surface points.
+		let ball = Ball::enclosing_points_auto_epsilon(&mut points.clone());
+		let ball_radius = ball.radius_squared.sqrt();
+		assert!(distance(&ball.center, &center) <= radius * 1e-5);
+		assert!((ball_radius - radius).abs() <= radius * 1e-5);
+		let all_on_surface = points
+			.iter()
+			.all(|point| (distance(point, &ball.center) - ball_radius).abs() <= radius * 1e-5);
+		assert!(all_on_surface);
+	}
+	assert!(misclassified > 0);
+}
@@ -0,0 +1,31 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(all(feature = "rayon", feature = "rand"))]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn best_of_parallel_3_balls_enclosing_co_spherical_shells() {
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	for _randomize in 0..10 {
+		// Outer and inner co-spherical shells.
+		let points = (0..1_000)
+			.map(|_point| Vector3::<f64>::new_random() - Vector3::from_element(0.5))
+			.enumerate()
+			.map(|(index, point)| point.normalize() * if index % 2 == 0 { 3.0 } else { 2.0 })
+			.map(|point| Point3::from(point + offset))
+			.collect::<Vec<_>>();
+		let serial = Ball::enclosing_points(&mut points.iter().copied().collect::<VecDeque<_>>());
+		let parallel = Ball::enclosing_points_best_of_parallel(&points, 8);
+		assert!(parallel.radius_squared <= serial.radius_squared * (1.0 + epsilon));
+		assert!(points.iter().all(|point| parallel.contains(point)));
+	}
+}
@@ -0,0 +1,32 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{distance, Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_and_approximate_3_ball_enclosing_3_cube() {
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	for _randomize in 0..100 {
+		let offset = Vector3::new(-3.0, 7.0, 4.8);
+		let mut points = (0..1_000)
+			.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+			.map(|point| point * 3.0 + offset)
+			.collect::<VecDeque<_>>();
+		let (exact, approximate) = Ball::enclosing_points_compare(&mut points);
+		assert_eq!(points.len(), 1_000);
+		assert!(approximate.radius_squared >= exact.radius_squared * (1.0 - epsilon));
+		for ball in [exact, approximate] {
+			let radius = ball.radius_squared.sqrt();
+			let all_enclosed = points
+				.iter()
+				.all(|point| distance(point, &ball.center) <= radius + epsilon);
+			assert!(all_enclosed);
+		}
+	}
+}
@@ -0,0 +1,33 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::Point2;
+use std::collections::VecDeque;
+
+#[test]
+fn fallback_2_ball_enclosing_huge_2_simplex() {
+	// Circumradius of 2-simplex (triangle) overflowing determinant of circumscribed 2-ball.
+	let radius = 1e100;
+	let mut points = [0.0, 120.0, 240.0]
+		.map(|angle: f64| Point2::new(angle.to_radians().cos(), angle.to_radians().sin()))
+		.map(|point| point * radius)
+		.into_iter()
+		.collect::<VecDeque<_>>();
+	// Welzl's algorithm exhausts its attempts.
+	let mut bounds = points.iter().take(3).copied().collect::<Vec<_>>();
+	assert!(Ball::with_bounds(&bounds).is_none());
+	bounds.rotate_left(1);
+	assert!(Ball::with_bounds(&bounds).is_none());
+	// Falls back to valid but non-minimum 2-ball.
+	let ball = Ball::enclosing_points(&mut points);
+	assert_eq!(points.len(), 3);
+	assert!(points.iter().all(|point| ball.contains(point)));
+	assert!(ball.radius_squared >= radius * radius);
+	assert!(ball.radius_squared <= radius * radius * 2.0);
+}
@@ -0,0 +1,39 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3, U3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_enclosing_flat_points() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<Vec<_>>();
+	let coords = points
+		.iter()
+		.flat_map(|point| point.coords.iter().copied())
+		.collect::<Vec<_>>();
+	let mut indices = (0..points.len()).collect::<VecDeque<_>>();
+	let ball = Ball::<_, U3>::enclosing_points_flat(&coords, &mut indices);
+	let mut permuted = points.iter().copied().collect::<VecDeque<_>>();
+	let minimum = Ball::enclosing_points(&mut permuted);
+	assert_eq!(ball, minimum);
+	// Indices are permuted like points.
+	assert!(indices
+		.iter()
+		.zip(&permuted)
+		.all(|(&index, point)| points[index] == *point));
+}
+
+#[test]
+#[should_panic(expected = "mismatching dimension")]
+fn mismatching_dimension() {
+	let coords = [0.0, 1.0, 2.0, 3.0];
+	let mut indices = VecDeque::from([0]);
+	let _ball = Ball::<f64, U3>::enclosing_points_flat(&coords, &mut indices);
+}
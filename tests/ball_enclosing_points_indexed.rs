@@ -0,0 +1,37 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Point6, Vector6};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_6_ball_enclosing_indexed_points() {
+	let offset = Vector6::<f64>::new(-3.0, 7.0, 4.8, 1.2, 5.3, 7.4);
+	let points = (0..1_000)
+		.map(|_point| Point6::from(Vector6::new_random() - Vector6::from_element(0.5) + offset))
+		.collect::<Vec<_>>();
+	let mut indices = (0..points.len()).collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points_indexed(&points, &mut indices);
+	let mut permuted = points.iter().copied().collect::<VecDeque<_>>();
+	let minimum = Ball::enclosing_points(&mut permuted);
+	assert_eq!(ball, minimum);
+	// Indices are permuted like points.
+	assert!(indices
+		.iter()
+		.zip(&permuted)
+		.all(|(&index, point)| points[index] == *point));
+}
+
+#[test]
+fn minimum_2_ball_enclosing_indexed_collinear_points() {
+	let points = (0..10)
+		.map(|x| Point2::<f64>::new(f64::from(x), 0.0))
+		.collect::<Vec<_>>();
+	let mut indices = (0..points.len()).rev().collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points_indexed(&points, &mut indices);
+	assert_eq!(ball, Ball::from_diameter(&points[0], &points[9]));
+}
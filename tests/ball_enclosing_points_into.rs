@@ -0,0 +1,29 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_enclosing_3_cube_into_output() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+		.map(|point| point * 3.0 + offset)
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points(&mut points.clone());
+	let mut out = Ball {
+		center: Point3::origin(),
+		radius_squared: 0.0,
+	};
+	Ball::enclosing_points_into(&mut points, &mut out);
+	assert_eq!(out.center, ball.center);
+	assert_eq!(out.radius_squared, ball.radius_squared);
+}
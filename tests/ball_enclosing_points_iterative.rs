@@ -0,0 +1,52 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_2_ball_enclosing_million_points_iteratively() {
+	// Uniform distribution in 2-cube centered around `offset` with room `diagonal_halved`.
+	let offset = Vector2::new(-3.0, 7.0);
+	let diagonal_halved = 3.0;
+	let mut points = (0..1_000_000)
+		.map(|_point| Point2::<f64>::from(Vector2::new_random() - Vector2::from_element(0.5)))
+		.map(|point| point * diagonal_halved / 2f64.sqrt())
+		.map(|point| point + offset)
+		.collect::<VecDeque<_>>();
+	// Insert 2 points of 2-cube's diagonal to enclose.
+	points.push_back(Point2::from(
+		Vector2::from_element(diagonal_halved / 2f64.sqrt()) + offset,
+	));
+	points.push_back(Point2::from(
+		-Vector2::from_element(diagonal_halved / 2f64.sqrt()) + offset,
+	));
+	let mut stack = VecDeque::new();
+	let ball = Ball::enclosing_points_iterative(&mut points, &mut stack);
+	assert!(stack.is_empty());
+	assert_eq!(points.len(), 1_000_002);
+	assert!((ball.center - Point2::from(offset)).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius() - diagonal_halved).abs() <= f64::EPSILON.sqrt());
+	assert!(points.iter().all(|point| ball.contains(point)));
+}
+
+#[test]
+fn minimum_3_ball_enclosing_points_iteratively_as_recursively() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random() + offset))
+		.collect::<VecDeque<_>>();
+	let mut iterative_points = points.clone();
+	let mut recursive_points = points;
+	let iterative = Ball::enclosing_points_iterative(&mut iterative_points, &mut VecDeque::new());
+	let recursive = Ball::enclosing_points(&mut recursive_points);
+	assert_eq!(iterative, recursive);
+	assert_eq!(iterative_points, recursive_points);
+}
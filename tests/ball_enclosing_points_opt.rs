@@ -0,0 +1,34 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Vector2};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_2_balls_enclosing_possibly_empty_clusters() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let mut clusters = [
+		vec![(-1.0, 0.0), (1.0, 0.0), (0.0, 0.5)],
+		vec![],
+		vec![(2.0, 2.0)],
+	]
+	.map(|cluster| {
+		cluster
+			.into_iter()
+			.map(|(x, y)| Point2::new(x, y) + offset)
+			.collect::<VecDeque<_>>()
+	});
+	let balls = clusters
+		.iter_mut()
+		.filter_map(Ball::enclosing_points_opt)
+		.collect::<Vec<_>>();
+	assert_eq!(balls.len(), 2);
+	assert_eq!(balls[0], Ball::new(Point2::from(offset), 1.0));
+	assert_eq!(balls[1], Ball::new(Point2::new(2.0, 2.0) + offset, 0.0));
+}
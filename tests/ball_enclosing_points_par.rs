@@ -0,0 +1,48 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "rayon")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use rayon::iter::IntoParallelRefMutIterator;
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_balls_enclosing_sets_in_parallel() {
+	let mut sets = (0..64)
+		.map(|set| {
+			let offset = Vector3::<f64>::new(-3.0, 7.0, f64::from(set));
+			(0..1_000)
+				.map(|_point| {
+					Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset)
+				})
+				.collect::<VecDeque<_>>()
+		})
+		.collect::<Vec<_>>();
+	let minima = sets
+		.iter()
+		.map(|points| Ball::enclosing_points(&mut points.clone()))
+		.collect::<Vec<_>>();
+	let balls = Ball::enclosing_points_par(sets.par_iter_mut());
+	assert_eq!(balls, minima);
+}
+
+#[test]
+fn minimum_3_balls_enclosing_large_sets_in_parallel() {
+	// Recursion exceeds the stack of worker threads unless spilled over to the heap.
+	let mut sets = (0..4)
+		.map(|_set| {
+			(0..200_000)
+				.map(|_point| Point3::<f64>::from(Vector3::new_random()))
+				.collect::<VecDeque<_>>()
+		})
+		.collect::<Vec<_>>();
+	let balls = Ball::enclosing_points_par(sets.par_iter_mut());
+	for (ball, points) in balls.iter().zip(&sets) {
+		assert!(points.iter().all(|point| ball.contains(point)));
+	}
+}
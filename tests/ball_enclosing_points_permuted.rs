@@ -0,0 +1,39 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn minimum_3_ball_enclosing_permuted_co_spherical_points() {
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Vector3::<f64>::new_random() - Vector3::from_element(0.5))
+		.map(|point| Point3::from(point.normalize() * 3.0 + offset))
+		.collect::<Vec<_>>();
+	let forward = (0..points.len()).collect::<Vec<_>>();
+	let backward = forward.iter().rev().copied().collect::<Vec<_>>();
+	let a = Ball::enclosing_points_permuted(&mut points.clone(), &forward);
+	let b = Ball::enclosing_points_permuted(&mut points.clone(), &backward);
+	// Same ball within epsilon, possibly with different bounds.
+	assert!((a.center - b.center).norm() <= epsilon);
+	assert!((a.radius_squared - b.radius_squared).abs() <= epsilon);
+	// Same order reproduces same ball.
+	let c = Ball::enclosing_points_permuted(&mut points, &backward);
+	assert_eq!(b, c);
+	assert_eq!(b.center, c.center);
+}
+
+#[test]
+#[should_panic(expected = "invalid permutation")]
+fn invalid_permutation() {
+	let mut points = vec![Point3::<f64>::origin(); 3];
+	let _ball = Ball::enclosing_points_permuted(&mut points, &[0, 2, 2]);
+}
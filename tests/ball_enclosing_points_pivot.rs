@@ -0,0 +1,116 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{distance, Point3, Vector3, U3};
+use std::{borrow::Borrow, cell::Cell, collections::VecDeque};
+
+thread_local! {
+	static WITH_BOUNDS_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Ball counting invocations of [`Enclosing::with_bounds()`].
+#[derive(Debug, Clone)]
+struct CountingBall(Ball<f64, U3>);
+
+impl Borrow<Ball<f64, U3>> for CountingBall {
+	fn borrow(&self) -> &Ball<f64, U3> {
+		&self.0
+	}
+}
+
+impl Enclosing<f64, U3> for CountingBall {
+	fn contains(&self, point: &Point3<f64>) -> bool {
+		self.0.contains(point)
+	}
+	fn with_bounds(bounds: &[Point3<f64>]) -> Option<Self> {
+		WITH_BOUNDS_CALLS.with(|calls| calls.set(calls.get() + 1));
+		Ball::with_bounds(bounds).map(Self)
+	}
+}
+
+#[test]
+fn minimum_3_ball_enclosing_3_cube_by_pivoting() {
+	// Epsilon of numerical stability for computing circumscribed 3-ball.
+	let epsilon = f64::EPSILON.sqrt();
+	for _randomize in 0..100 {
+		// Uniform distribution in 3-cube centered around `offset`.
+		let offset = Vector3::new(-3.0, 7.0, 4.8);
+		let mut points = (0..1_000)
+			.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+			.map(|point| point * 3.0 + offset)
+			.collect::<Vec<_>>();
+		let mut permuted = points.iter().copied().collect::<VecDeque<_>>();
+		let basic = Ball::enclosing_points(&mut permuted);
+		let pivot = Ball::enclosing_points_pivot(&mut points);
+		assert_eq!(permuted.len(), points.len());
+		// Ensures balls match within numerical stability.
+		assert!((pivot.radius_squared / basic.radius_squared - 1.0).abs() <= epsilon);
+		assert!(distance(&pivot.center, &basic.center) <= epsilon);
+		// Ensures all points are enclosed by 3-ball.
+		let radius = pivot.radius_squared.sqrt();
+		let all_enclosed = points
+			.iter()
+			.all(|point| distance(point, &pivot.center) <= radius + epsilon);
+		assert!(all_enclosed);
+	}
+}
+
+#[test]
+fn fewer_with_bounds_calls_by_pivoting_clustered_points() {
+	let (mut basic_calls, mut pivot_calls) = (0, 0);
+	for _randomize in 0..10 {
+		// Two distant clusters of uniform distributions in 3-cube.
+		let mut points = (0..1_000)
+			.map(|point| {
+				let offset = Vector3::new(if point % 2 == 0 { -10.0 } else { 10.0 }, 0.0, 0.0);
+				Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5) + offset)
+			})
+			.collect::<Vec<_>>();
+		WITH_BOUNDS_CALLS.with(|calls| calls.set(0));
+		let basic =
+			CountingBall::enclosing_points(&mut points.iter().copied().collect::<VecDeque<_>>());
+		basic_calls += WITH_BOUNDS_CALLS.with(Cell::get);
+		WITH_BOUNDS_CALLS.with(|calls| calls.set(0));
+		let pivot = CountingBall::enclosing_points_pivot(&mut points);
+		pivot_calls += WITH_BOUNDS_CALLS.with(Cell::get);
+		assert!(
+			(pivot.0.radius_squared / basic.0.radius_squared - 1.0).abs() <= f64::EPSILON.sqrt()
+		);
+	}
+	assert!(pivot_calls < basic_calls);
+}
+
+/// Ball excluding its own bounds as if due to rounding.
+#[derive(Debug, Clone)]
+struct ShrunkBall(Ball<f64, U3>);
+
+impl Borrow<Ball<f64, U3>> for ShrunkBall {
+	fn borrow(&self) -> &Ball<f64, U3> {
+		&self.0
+	}
+}
+
+impl Enclosing<f64, U3> for ShrunkBall {
+	fn contains(&self, point: &Point3<f64>) -> bool {
+		distance(point, &self.0.center) <= self.0.radius()
+	}
+	fn with_bounds(bounds: &[Point3<f64>]) -> Option<Self> {
+		Ball::with_bounds(bounds).map(|ball| Self(ball.shrink_by(1e-3)))
+	}
+}
+
+#[test]
+fn pivoting_falls_back_if_ball_excludes_prefix() {
+	let mut points = (0..100)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random()))
+		.collect::<Vec<_>>();
+	let basic = ShrunkBall::enclosing_points(&mut points.iter().copied().collect::<VecDeque<_>>());
+	let pivot = ShrunkBall::enclosing_points_pivot(&mut points);
+	assert!((pivot.0.radius() - basic.0.radius()).abs() <= 1e-2);
+}
@@ -0,0 +1,52 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn quantile_2_ball_about_centroid_excluding_outlier() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let mut points = (0..9)
+		.map(|step| f64::from(step) * 40f64.to_radians())
+		.map(|angle| Point2::new(angle.cos(), angle.sin()))
+		.collect::<Vec<_>>();
+	points.insert(4, Point2::new(100.0, 0.0));
+	let points = points
+		.into_iter()
+		.map(|point| point + offset)
+		.collect::<Vec<_>>();
+	let ball = Ball::enclosing_points_quantile(&points, true, 0.9);
+	let (outlier, inliers) = (&points[4], [&points[..4], &points[5..]].concat());
+	assert!(inliers.iter().all(|point| ball.contains(point)));
+	assert!(!ball.contains(outlier));
+	let ball = Ball::enclosing_points_quantile(&points, true, 1.0);
+	assert!(points.iter().all(|point| ball.contains(point)));
+}
+
+#[test]
+fn quantile_2_ball_about_enclosing_center() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let points = (1..=5)
+		.flat_map(|step| [f64::from(step), -f64::from(step)])
+		.map(|step| Point2::new(step, 0.0) + offset)
+		.collect::<Vec<_>>();
+	let Ball {
+		center,
+		radius_squared,
+	} = Ball::enclosing_points_quantile(&points, false, 0.8);
+	assert_eq!(center, offset.into());
+	assert_eq!(radius_squared, 16.0);
+	let Ball {
+		center,
+		radius_squared,
+	} = Ball::enclosing_points_quantile(&points, false, 0.75);
+	assert_eq!(center, offset.into());
+	assert_eq!(radius_squared, 16.0);
+}
@@ -0,0 +1,31 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_with_quantized_radius_enclosing_3_cube() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let radius_step = 0.25;
+	for _randomize in 0..100 {
+		let mut points = (0..1_000)
+			.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+			.map(|point| point * 3.0 + offset)
+			.collect::<VecDeque<_>>();
+		let exact = Ball::enclosing_points(&mut points);
+		let ball = Ball::enclosing_points_quantized_radius(&mut points, radius_step);
+		let (exact_radius, radius) = (exact.radius_squared.sqrt(), ball.radius_squared.sqrt());
+		// Next step up.
+		assert_eq!((radius / radius_step).round() * radius_step, radius);
+		assert!(radius >= exact_radius && radius - radius_step < exact_radius);
+		assert!(points.iter().all(|point| ball.contains(point)));
+	}
+}
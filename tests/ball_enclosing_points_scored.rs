@@ -0,0 +1,57 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Vector2};
+use std::collections::VecDeque;
+
+#[test]
+fn well_conditioned_2_ball_scores_high() {
+	let offset = Vector2::new(-3.0, 7.0);
+	// Equilateral triangle.
+	let mut points = [0.0, 120.0, 240.0]
+		.map(|angle: f64| Point2::new(angle.to_radians().cos(), angle.to_radians().sin()))
+		.map(|point| point * 3.0 + offset)
+		.into_iter()
+		.collect::<VecDeque<_>>();
+	let (ball, score) = Ball::enclosing_points_scored(&mut points);
+	assert!((ball.radius_squared - 9.0).abs() <= 1e-12);
+	assert!(score >= 0.25);
+}
+
+#[test]
+fn ill_conditioned_2_ball_scores_low() {
+	let offset = Vector2::new(-3.0, 7.0);
+	// Thin acute isosceles triangle circumscribed by all its vertices.
+	let mut points = [
+		Point2::new(0.0, 1e-3),
+		Point2::new(0.0, -1e-3),
+		Point2::new(3.0, 0.0),
+	]
+	.map(|point| point + offset)
+	.into_iter()
+	.collect::<VecDeque<_>>();
+	let (ball, score) = Ball::enclosing_points_scored(&mut points);
+	assert!(points.iter().all(|point| ball.contains(point)));
+	assert!(score <= 1e-3);
+}
+
+#[test]
+fn collinear_2_ball_scores_like_enclosing_points() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let direction = Vector2::new(1.0, -2.0);
+	let mut points = [0.5, -1.5, 2.0, 0.0, -0.5]
+		.map(|step| Point2::from(offset + direction * step))
+		.into_iter()
+		.collect::<VecDeque<_>>();
+	let basic = Ball::enclosing_points(&mut points.clone());
+	let (ball, score) = Ball::enclosing_points_scored(&mut points);
+	assert_eq!(ball, basic);
+	assert_eq!(score, 1.0);
+}
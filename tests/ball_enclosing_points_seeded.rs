@@ -0,0 +1,28 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(all(feature = "std", feature = "rand"))]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::VecDeque;
+
+#[test]
+fn reproducible_minimum_3_ball_enclosing_seeded_points() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random() + offset))
+		.collect::<VecDeque<_>>();
+	let mut a = points.clone();
+	let mut b = points.clone();
+	let ball_a = Ball::enclosing_points_seeded(&mut a, &mut StdRng::seed_from_u64(42));
+	let ball_b = Ball::enclosing_points_seeded(&mut b, &mut StdRng::seed_from_u64(42));
+	assert_eq!(ball_a, ball_b);
+	assert_eq!(a, b);
+	assert_ne!(a, points);
+	assert!(points.iter().all(|point| ball_a.contains(point)));
+}
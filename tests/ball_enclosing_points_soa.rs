@@ -0,0 +1,43 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3, U3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_enclosing_3_cube_as_soa() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+		.map(|point| point * 3.0 + offset)
+		.collect::<Vec<_>>();
+	let coords = (0..3)
+		.map(|row| points.iter().map(|point| point[row]).collect::<Vec<_>>())
+		.collect::<Vec<_>>();
+	let soa = Ball::enclosing_points_soa(&coords, &mut (0..1_000).collect::<VecDeque<_>>());
+	let aos = Ball::enclosing_points(&mut points.into_iter().collect::<VecDeque<_>>());
+	assert_eq!(soa.center, aos.center);
+	assert_eq!(soa.radius_squared, aos.radius_squared);
+}
+
+#[test]
+#[should_panic(expected = "mismatching dimension")]
+fn minimum_3_ball_enclosing_2_columns() {
+	let mut indices = VecDeque::from([0]);
+	let _ball = Ball::<f64, U3>::enclosing_points_soa(&[[1.0], [2.0]], &mut indices);
+}
+
+#[test]
+#[should_panic(expected = "mismatching columns")]
+fn minimum_3_ball_enclosing_ragged_columns() {
+	let mut indices = VecDeque::from([0]);
+	let _ball =
+		Ball::<f64, U3>::enclosing_points_soa(&[vec![1.0], vec![2.0], vec![]], &mut indices);
+}
@@ -0,0 +1,53 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3, U3};
+use std::{cell::Cell, collections::VecDeque};
+
+thread_local! {
+	static WITH_BOUNDS_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Ball counting invocations of [`Enclosing::with_bounds()`].
+#[derive(Debug, Clone)]
+struct CountingBall(Ball<f64, U3>);
+
+impl Enclosing<f64, U3> for CountingBall {
+	fn contains(&self, point: &Point3<f64>) -> bool {
+		self.0.contains(point)
+	}
+	fn with_bounds(bounds: &[Point3<f64>]) -> Option<Self> {
+		WITH_BOUNDS_CALLS.with(|calls| calls.set(calls.get() + 1));
+		Ball::with_bounds(bounds).map(Self)
+	}
+}
+
+#[test]
+fn warm_minimum_3_ball_enclosing_added_points() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<VecDeque<_>>();
+	let previous = CountingBall::enclosing_points(&mut points);
+	// Interior point takes fast path without circumscribing any ball.
+	points.push_back(Point3::from(offset));
+	WITH_BOUNDS_CALLS.with(|calls| calls.set(0));
+	let ball = CountingBall::enclosing_points_warm(&mut points, &previous);
+	assert_eq!(WITH_BOUNDS_CALLS.with(Cell::get), 0);
+	assert_eq!(ball.0, previous.0);
+	assert_eq!(points.len(), 1_001);
+	// Exterior point falls back to full algorithm.
+	let exterior = Point3::new(1.0, 0.0, 0.0) + offset;
+	points.push_back(exterior);
+	let ball = CountingBall::enclosing_points_warm(&mut points, &previous);
+	assert!(WITH_BOUNDS_CALLS.with(Cell::get) > 0);
+	assert!(ball.0.radius_squared > previous.0.radius_squared);
+	assert!(points.iter().all(|point| ball.contains(point)));
+	assert_eq!(points.len(), 1_002);
+}
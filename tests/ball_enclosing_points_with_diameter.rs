@@ -0,0 +1,50 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{distance, Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_and_diameter_enclosing_3_simplex() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	// Irregular 3-simplex (tetrahedron) with all vertices on surface.
+	let vertices = [
+		Point3::new(3.0, 0.0, 0.0),
+		Point3::new(-1.0, 2.5, 0.5),
+		Point3::new(-1.0, -2.0, 1.5),
+		Point3::new(0.5, 0.5, -2.5),
+	]
+	.map(|vertex| vertex + offset);
+	let max_distance = vertices
+		.iter()
+		.flat_map(|a| vertices.iter().map(move |b| distance(a, b)))
+		.fold(0.0, f64::max);
+	let mut points = vertices.into_iter().collect::<VecDeque<_>>();
+	let (ball, (a, b)) = Ball::enclosing_points_with_diameter(&mut points);
+	assert!(vertices.iter().all(|point| ball.contains(point)));
+	assert!(vertices.contains(&a));
+	assert!(vertices.contains(&b));
+	assert_eq!(distance(&a, &b), max_distance);
+}
+
+#[test]
+fn minimum_3_ball_and_diameter_enclosing_collinear_points() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let direction = Vector3::new(1.0, -2.0, 0.5);
+	let mut points = [0.5, -1.5, 2.0, 0.0, -0.5]
+		.map(|step| Point3::from(offset + direction * step))
+		.into_iter()
+		.collect::<VecDeque<_>>();
+	let basic = Ball::enclosing_points(&mut points.clone());
+	let (ball, (a, b)) = Ball::enclosing_points_with_diameter(&mut points);
+	assert_eq!(ball, basic);
+	let (min, max) = (offset + direction * -1.5, offset + direction * 2.0);
+	assert!([(a, b), (b, a)].contains(&(min.into(), max.into())));
+}
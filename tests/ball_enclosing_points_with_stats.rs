@@ -0,0 +1,42 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_enclosing_points_with_stats() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<VecDeque<_>>();
+	let minimum = Ball::enclosing_points(&mut points.clone());
+	let (ball, stats) = Ball::enclosing_points_with_stats(&mut points);
+	assert_eq!(points.len(), 1_000);
+	assert_eq!(ball.center, minimum.center);
+	assert_eq!(ball.radius_squared, minimum.radius_squared);
+	// First branch takes all points.
+	assert_eq!(stats.max_depth, 1_000);
+	assert!(stats.with_bounds_calls > 1);
+	assert!(!stats.red_zone_entered);
+	// Move-to-front heuristic reduces cost of recomputation.
+	let (_ball, again) = Ball::enclosing_points_with_stats(&mut points);
+	assert!(again.with_bounds_calls <= stats.with_bounds_calls);
+}
+
+#[test]
+fn minimum_2_ball_enclosing_collinear_points_with_stats() {
+	let mut points = (0..10)
+		.map(|x| Point2::<f64>::new(f64::from(x), 0.0))
+		.collect::<VecDeque<_>>();
+	let (ball, stats) = Ball::enclosing_points_with_stats(&mut points);
+	assert_eq!(ball.center, Point2::new(4.5, 0.0));
+	assert_eq!(stats.with_bounds_calls, 1);
+	assert_eq!(stats.max_depth, 0);
+}
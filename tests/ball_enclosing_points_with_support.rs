@@ -0,0 +1,65 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point, Point1, Point3, Vector3};
+use std::collections::VecDeque;
+
+fn assert_on_surface<const D: usize>(
+	ball: &Ball<f64, nalgebra::Const<D>>,
+	support: &[Point<f64, D>],
+) {
+	let epsilon = f64::EPSILON.sqrt();
+	for point in support {
+		let distance = (point - ball.center).norm_squared();
+		assert!((distance - ball.radius_squared).abs() <= epsilon * ball.radius_squared.max(1.0));
+	}
+}
+
+#[test]
+fn minimum_3_ball_enclosing_3_cube_with_support() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let mut points = VecDeque::new();
+	for x in [-1.0, 1.0] {
+		for y in [-1.0, 1.0] {
+			for z in [-1.0, 1.0] {
+				points.push_back(Point3::new(x, y, z) + offset);
+			}
+		}
+	}
+	points.push_back(Point3::from(offset));
+	let (ball, support) = Ball::enclosing_points_with_support(&mut points);
+	assert_eq!(points.len(), 9);
+	assert!((2..=4).contains(&support.len()));
+	assert!(support.iter().all(|point| points.contains(point)));
+	assert_on_surface(&ball, support.as_slice());
+}
+
+#[test]
+fn minimum_1_ball_enclosing_single_point_with_support() {
+	let point = Point1::new(4.0);
+	let mut points = VecDeque::from([point]);
+	let (ball, support) = Ball::enclosing_points_with_support(&mut points);
+	assert_eq!(support.as_slice(), [point]);
+	assert_eq!(ball.center, point);
+	assert_eq!(ball.radius_squared, 0.0);
+}
+
+#[test]
+fn minimum_3_ball_enclosing_3_line_with_support() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let direction = Vector3::new(1.0, 2.0, -0.5);
+	let mut points = (0..10)
+		.map(|step| Point3::from(offset + direction * f64::from(step)))
+		.collect::<VecDeque<_>>();
+	let (ball, support) = Ball::enclosing_points_with_support(&mut points);
+	assert_eq!(support.len(), 2);
+	assert_on_surface(&ball, support.as_slice());
+	assert!((ball.center.coords - (offset + direction * 4.5)).norm() <= f64::EPSILON.sqrt());
+}
@@ -0,0 +1,37 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use core::f64::consts::TAU;
+use miniball::Ball;
+use nalgebra::{distance, Point2, Vector2};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_2_ball_enclosing_noisy_circle_with_tolerance() {
+	// Points on unit circle with radial noise of relative amplitude `1e-4`.
+	let offset = Vector2::new(-3.0, 7.0);
+	let points = (0..100)
+		.map(|step| {
+			let angle = f64::from(step) / 100.0 * TAU;
+			let radius = (f64::from(step) * 7.0).sin().mul_add(1e-4, 1.0);
+			Point2::new(angle.cos(), angle.sin()) * radius + offset
+		})
+		.collect::<VecDeque<_>>();
+	// Maximum relative deviation of points outside the surface.
+	let deviation = |ball: &Ball<f64, _>| {
+		points
+			.iter()
+			.map(|point| distance(point, &ball.center) / ball.radius() - 1.0)
+			.fold(0.0, f64::max)
+	};
+	let loose = Ball::enclosing_points_with_tolerance(&mut points.clone(), 1e-2);
+	let tight = Ball::enclosing_points_with_tolerance(&mut points.clone(), 1e-12);
+	assert!(tight.contains_with_tolerance(&points[0], 1e-12));
+	assert!(deviation(&tight) <= 1e-12);
+	assert!(deviation(&tight) < deviation(&loose));
+}
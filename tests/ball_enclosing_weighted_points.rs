@@ -0,0 +1,48 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_enclosing_equally_weighted_points() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<VecDeque<_>>();
+	let weight = 0.125;
+	let mut weighted_points = points
+		.iter()
+		.map(|&point| (point, weight))
+		.collect::<VecDeque<_>>();
+	let minimum = Ball::enclosing_points(&mut points);
+	let ball = Ball::enclosing_weighted_points(&mut weighted_points);
+	assert_eq!(weighted_points.len(), 1_000);
+	assert!((ball.center - minimum.center).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius_squared + weight - minimum.radius_squared).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+fn minimum_2_ball_enclosing_weighted_points() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	// Power distances `(x + 1)²` and `(x - 1)² - 1` are equal at `x = -1/4`.
+	let mut points = VecDeque::from([
+		(Point2::new(-1.0, 0.0) + offset, 0.0),
+		(Point2::new(1.0, 0.0) + offset, 1.0),
+	]);
+	let ball = Ball::enclosing_weighted_points(&mut points);
+	assert!((ball.center - (Point2::new(-0.25, 0.0) + offset)).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius_squared - 0.5625).abs() <= f64::EPSILON.sqrt());
+	// Dominant weight is enclosed without being a bound.
+	let mut points = VecDeque::from([
+		(Point2::new(0.0, 0.0) + offset, 10.0),
+		(Point2::new(1.0, 0.0) + offset, 0.0),
+	]);
+	let ball = Ball::enclosing_weighted_points(&mut points);
+	assert!((ball.center - (Point2::new(1.0, 0.0) + offset)).norm() <= f64::EPSILON.sqrt());
+	assert!(ball.radius_squared.abs() <= f64::EPSILON.sqrt());
+}
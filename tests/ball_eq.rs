@@ -0,0 +1,34 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::cmp::Ordering;
+use miniball::Ball;
+use nalgebra::Point2;
+
+#[test]
+fn equal_2_balls_compare_centers_and_radii() {
+	let ball = Ball::new(Point2::<f64>::new(-3.0, 7.0), 2.0);
+	assert_eq!(ball, Ball::new(Point2::new(-3.0, 7.0), 2.0));
+	assert_ne!(ball, Ball::new(Point2::new(3.0, 7.0), 2.0));
+	assert_ne!(ball, Ball::new(Point2::new(-3.0, 7.0), 3.0));
+	let (a, b) = (
+		Ball::new(ball.center, 0.0),
+		Ball::new(Point2::origin(), 0.0),
+	);
+	assert_ne!(a, b);
+}
+
+#[test]
+fn compare_2_balls_by_radius() {
+	let ball = Ball::new(Point2::<f64>::new(-3.0, 7.0), 2.0);
+	let other = Ball::new(Point2::new(3.0, 7.0), 2.0);
+	assert_eq!(ball.cmp_radius(&other), Ordering::Equal);
+	let other = Ball::new(Point2::new(-3.0, 7.0), 3.0);
+	assert_eq!(ball.cmp_radius(&other), Ordering::Less);
+	assert_eq!(other.cmp_radius(&ball), Ordering::Greater);
+	let smallest = [other, ball].into_iter().min_by(Ball::cmp_radius);
+	assert_eq!(smallest, Some(ball));
+}
@@ -0,0 +1,29 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn expand_2_ball_to_contain_points() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	// Single point grows to diameter of two points.
+	let mut ball = Ball::new(Point2::new(-1.0, 0.0) + offset, 0.0);
+	ball.expand_to_contain(&(Point2::new(1.0, 0.0) + offset));
+	assert!((ball.center - Point2::from(offset)).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius_squared - 1.0).abs() <= f64::EPSILON.sqrt());
+	// Contained point leaves ball untouched.
+	let previous = ball;
+	ball.expand_to_contain(&(Point2::new(0.5, 0.5) + offset));
+	assert_eq!(ball, previous);
+	// Outside point grows ball keeping far side on surface.
+	ball.expand_to_contain(&(Point2::new(0.0, 3.0) + offset));
+	assert!((ball.radius() - 2.0).abs() <= f64::EPSILON.sqrt());
+	assert!((ball.center - (Point2::new(0.0, 1.0) + offset)).norm() <= f64::EPSILON.sqrt());
+	for point in [(-1.0, 0.0), (1.0, 0.0), (0.0, -1.0), (0.0, 3.0)] {
+		assert!(ball.contains(&(Point2::new(point.0, point.1) + offset)));
+	}
+}
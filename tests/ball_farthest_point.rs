@@ -0,0 +1,26 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn farthest_and_nearest_point_of_2_ball() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let ball = Ball::new(Point2::from(offset), 5.0);
+	let points = [(3.0, 0.0), (0.0, -4.0), (1.0, 0.0), (0.0, 4.0), (0.0, -1.0)]
+		.map(|(x, y)| Point2::new(x, y) + offset);
+	let (farthest, distance) = ball.farthest_point(&points).unwrap();
+	assert!(std::ptr::eq(farthest, &points[1]));
+	assert_eq!(distance, 4.0);
+	let (nearest, distance) = ball.nearest_point(&points).unwrap();
+	assert!(std::ptr::eq(nearest, &points[2]));
+	assert_eq!(distance, 1.0);
+	assert!(ball.farthest_point(&[]).is_none());
+	assert!(ball.nearest_point(&[]).is_none());
+}
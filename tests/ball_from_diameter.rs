@@ -0,0 +1,22 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Location};
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn minimum_3_ball_from_diameter() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let a = Point3::new(1.0, 2.0, -2.0) + offset;
+	let b = Point3::new(-1.0, -2.0, 2.0) + offset;
+	let ball = Ball::from_diameter(&a, &b);
+	assert_eq!(ball.center, offset.into());
+	assert_eq!(ball.radius_squared, 9.0);
+	assert_eq!(ball.locate(&a), Location::Surface);
+	assert_eq!(ball.locate(&b), Location::Surface);
+}
@@ -0,0 +1,23 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_from_points() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<Vec<_>>();
+	let ball = Ball::from_points(points.iter().copied());
+	let minimum = Ball::enclosing_points(&mut points.into_iter().collect::<VecDeque<_>>());
+	assert_eq!(ball.center, minimum.center);
+	assert_eq!(ball.radius_squared, minimum.radius_squared);
+}
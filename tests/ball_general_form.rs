@@ -0,0 +1,41 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn general_form() {
+	let ball = Ball::new(Point3::<f64>::new(1.0, -2.0, 3.0), 2.0);
+	let (a, b, c) = ball.general_form();
+	assert_eq!(a, 1.0);
+	assert_eq!(b, Vector3::new(-2.0, 4.0, -6.0));
+	assert_eq!(c, 14.0 - 4.0);
+	let point = Point3::new(3.0, -2.0, 3.0);
+	assert_eq!(
+		a.mul_add(point.coords.norm_squared(), b.dot(&point.coords)) + c,
+		0.0
+	);
+	assert_eq!(ball.power_distance(&Point3::origin()), c);
+}
+
+#[test]
+fn from_general_form() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	for _ in 0..100 {
+		let center =
+			Point3::from(Vector3::<f64>::new_random() - Vector3::from_element(0.5) + offset);
+		let ball = Ball::from_radius_squared(center, Vector3::<f64>::new_random().x + 0.5);
+		let (a, b, c) = ball.general_form();
+		let same = Ball::from_general_form(a, &b, c);
+		assert!(same.approx_eq(&ball, 1e-12));
+		let scale = -2.5;
+		let same = Ball::from_general_form(a * scale, &(b * scale), c * scale);
+		assert!(same.approx_eq(&ball, 1e-12));
+	}
+}
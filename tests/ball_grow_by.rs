@@ -0,0 +1,30 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::Point3;
+
+#[test]
+fn grown_and_shrunk_3_ball_by_same_margin() {
+	let ball = Ball::new(Point3::<f64>::new(-3.0, 7.0, 4.8), 1.7);
+	let grown = ball.grow_by(0.3);
+	assert_eq!(grown.center, ball.center);
+	assert!((grown.radius() - 2.0).abs() < f64::EPSILON.sqrt());
+	let shrunk = grown.shrink_by(0.3);
+	assert_eq!(shrunk.center, ball.center);
+	assert!((shrunk.radius() - ball.radius()).abs() < f64::EPSILON.sqrt());
+}
+
+#[test]
+fn over_shrunk_3_ball_clamped_at_zero() {
+	let ball = Ball::new(Point3::<f64>::new(-3.0, 7.0, 4.8), 1.7);
+	let shrunk = ball.shrink_by(2.0);
+	assert_eq!(shrunk.center, ball.center);
+	assert_eq!(shrunk.radius_squared, 0.0);
+	assert_eq!(ball.grow_by(-2.0).radius_squared, 0.0);
+}
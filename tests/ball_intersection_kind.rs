@@ -0,0 +1,83 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Intersection};
+use nalgebra::{Point2, Point3, Point4, Vector3};
+
+#[test]
+fn intersection_kinds_of_3_balls() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = |(x, y, z), radius| Ball::new(Point3::new(x, y, z) + offset, radius);
+	let unit = ball((0.0, 0.0, 0.0), 1.0);
+	for (other, kind) in [
+		// Concentric.
+		(ball((0.0, 0.0, 0.0), 2.0), Intersection::Contained),
+		(ball((0.0, 0.0, 0.0), 1.0), Intersection::Contained),
+		// Externally tangent.
+		(ball((0.0, 3.0, 0.0), 2.0), Intersection::Touching),
+		// Fully and internally tangent inside.
+		(ball((0.0, 0.0, 0.25), 0.5), Intersection::Contained),
+		(ball((0.0, 0.0, 0.5), 0.5), Intersection::Contained),
+		(ball((0.0, 0.0, 1.0), 2.0), Intersection::Contained),
+		(ball((0.0, 1.5, 0.0), 1.0), Intersection::Overlapping),
+		(ball((4.0, 0.0, 0.0), 2.0), Intersection::Disjoint),
+	] {
+		assert_eq!(unit.intersection_kind(&other), kind);
+		assert_eq!(other.intersection_kind(&unit), kind);
+		assert_eq!(unit.intersects(&other), kind != Intersection::Disjoint);
+	}
+}
+
+#[test]
+fn intersection_kinds_of_degenerate_balls() {
+	let ball = Ball::new(Point2::new(0.0, 0.0), 2.0);
+	let point = |x, y| Ball::new(Point2::new(x, y), 0.0);
+	assert_eq!(
+		ball.intersection_kind(&point(1.0, 1.0)),
+		Intersection::Contained
+	);
+	assert_eq!(
+		ball.intersection_kind(&point(2.0, 0.0)),
+		Intersection::Contained
+	);
+	assert_eq!(
+		ball.intersection_kind(&point(3.0, 0.0)),
+		Intersection::Disjoint
+	);
+	assert_eq!(
+		point(1.0, 1.0).intersection_kind(&point(1.0, 1.0)),
+		Intersection::Contained
+	);
+	assert_eq!(
+		point(1.0, 1.0).intersection_kind(&point(1.0, 2.0)),
+		Intersection::Disjoint
+	);
+	let ball = Ball::new(Point4::new(0.0, 0.0, 0.0, 0.0), 1.0);
+	let other = Ball::new(Point4::new(0.0, 0.0, 0.0, 2.0), 1.0);
+	assert_eq!(ball.intersection_kind(&other), Intersection::Touching);
+}
+
+#[test]
+fn intersection_kinds_of_nearly_touching_3_balls() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = |(x, y, z), radius| Ball::new(Point3::new(x, y, z) + offset, radius);
+	let unit = ball((0.0, 0.0, 0.0), 1.0);
+	for (other, kind) in [
+		// Externally tangent up to rounding errors of inexact coordinates and radii.
+		(
+			ball((0.6 * 1.3, 0.8 * 1.3, 0.0), 0.3),
+			Intersection::Touching,
+		),
+		(ball((0.0, 1.2 + 1e-12, 0.0), 0.2), Intersection::Touching),
+		(ball((0.0, 1.2 - 1e-12, 0.0), 0.2), Intersection::Touching),
+		// Apart or overlapping beyond the tolerance.
+		(ball((0.0, 1.2 + 1e-3, 0.0), 0.2), Intersection::Disjoint),
+		(ball((0.0, 1.2 - 1e-3, 0.0), 0.2), Intersection::Overlapping),
+	] {
+		assert_eq!(unit.intersection_kind(&other), kind);
+		assert_eq!(other.intersection_kind(&unit), kind);
+	}
+}
@@ -0,0 +1,33 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::Ball;
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn intersecting_pairs_of_2_balls() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let balls = [
+		((0.0, 0.0), 1.0),
+		((5.0, 0.0), 1.0),
+		((1.5, 0.0), 1.0),
+		((0.0, 1.5), 0.25),
+		((10.0, 0.0), 4.0),
+		((2.0, 5.0), 1.0),
+		((4.0, 0.0), 1.0),
+		((10.5, 0.5), 0.25),
+	]
+	.map(|((x, y), radius_squared)| Ball {
+		center: Point2::new(x, y) + offset,
+		radius_squared,
+	});
+	// Overlapping, touching, and containing pairs but none only overlapping along first axis.
+	let pairs = Ball::intersection_matrix(&balls);
+	assert_eq!(pairs, [(0, 2), (0, 3), (1, 6), (4, 7)]);
+	assert!(Ball::<f64, _>::intersection_matrix(&balls[..0]).is_empty());
+}
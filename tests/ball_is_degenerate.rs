@@ -0,0 +1,38 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing, Location};
+use nalgebra::{Point2, Vector2};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_2_ball_enclosing_identical_points_is_degenerate() {
+	let point = Point2::<f64>::new(-3.0, 7.0);
+	let ball = Ball::enclosing_points(&mut VecDeque::from([point; 3]));
+	assert!(ball.is_degenerate());
+	assert!(ball.is_degenerate_with_tolerance(0.0));
+	assert_eq!(ball.locate(&point), Location::Surface);
+	assert!(ball.contains(&point));
+	let near = point + Vector2::new(1e-12, 0.0);
+	assert_eq!(ball.locate(&near), Location::Outside);
+	assert!(!ball.contains(&near));
+	let ball = Ball::new(point, 1e-9);
+	assert!(!ball.is_degenerate());
+	assert!(ball.is_degenerate_with_tolerance(1e-6));
+	assert!(!ball.is_degenerate_with_tolerance(1e-12));
+}
+
+#[test]
+fn approximately_equal_2_balls() {
+	let ball = Ball::new(Point2::<f64>::new(-3.0, 7.0), 2.0);
+	let other = Ball::new(Point2::new(-3.0, 7.0 + 1e-9), 2.0 + 1e-9);
+	assert!(ball.approx_eq(&other, 1e-6));
+	assert!(!ball.approx_eq(&other, 1e-12));
+	let other = Ball::new(Point2::new(3.0, 7.0), 2.0);
+	assert!(!ball.approx_eq(&other, 1e-6));
+	let (a, b) = (Ball::new(ball.center, 0.0), Ball::new(other.center, 0.0));
+	assert!(!a.approx_eq(&b, 1e-6));
+}
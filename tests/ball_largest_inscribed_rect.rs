@@ -0,0 +1,25 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::Point2;
+
+#[test]
+fn largest_rects_inscribed_in_2_ball() {
+	let ball = Ball {
+		center: Point2::<f64>::new(-3.0, 7.0),
+		radius_squared: 9.0,
+	};
+	// Square with diagonal being diameter.
+	let (center, width, height) = ball.largest_inscribed_rect(1.0);
+	assert_eq!(center, ball.center);
+	assert!((width - height).abs() <= 1e-12);
+	assert!((width.hypot(height) - 6.0).abs() <= 1e-12);
+	// Rectangle of aspect ratio 16:9.
+	let (_center, width, height) = ball.largest_inscribed_rect(16.0 / 9.0);
+	assert!((width / height - 16.0 / 9.0).abs() <= 1e-12);
+	assert!((width.hypot(height) - 6.0).abs() <= 1e-12);
+}
@@ -0,0 +1,24 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::Point3;
+
+#[test]
+fn interpolated_3_balls() {
+	let a = Ball::new(Point3::<f64>::new(-3.0, 7.0, 4.5), 1.0);
+	let b = Ball::new(Point3::new(1.0, 3.0, -0.5), 3.0);
+	assert_eq!(a.lerp(&b, 0.0), a);
+	assert_eq!(a.lerp(&b, 1.0), b);
+	let midpoint = a.lerp(&b, 0.5);
+	assert_eq!(midpoint.center, Point3::new(-1.0, 5.0, 2.0));
+	assert_eq!(midpoint.radius_squared, 4.0);
+	let extrapolated = a.lerp(&b, -1.0);
+	assert_eq!(extrapolated.center, Point3::new(-7.0, 11.0, 9.5));
+	assert_eq!(extrapolated.radius_squared, 0.0);
+}
@@ -0,0 +1,38 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing, Location};
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn locate_points_relative_to_2_ball() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let ball = Ball::new(Point2::from(offset), 2.0);
+	for (point, location) in [
+		((0.0, 0.0), Location::Inside),
+		((1.0, -1.0), Location::Inside),
+		((0.0, 2.0), Location::Surface),
+		((-2.0 * (1.0 + 1e-10), 0.0), Location::Surface),
+		((2.0 * (1.0 - 1e-10), 0.0), Location::Surface),
+		((0.0, -2.1), Location::Outside),
+	] {
+		let point = Point2::new(point.0, point.1) + offset;
+		assert_eq!(ball.locate(&point), location);
+		assert_eq!(ball.contains(&point), location != Location::Outside);
+	}
+	assert_eq!(
+		ball.locate_with_tolerance(&(Point2::new(0.0, 2.1) + offset), 0.1),
+		Location::Surface
+	);
+}
+
+#[test]
+fn locate_center_of_0_radius_2_ball() {
+	let ball = Ball::new(Point2::new(-3.0, 7.0), 0.0);
+	assert_eq!(ball.locate(&ball.center), Location::Surface);
+	assert!(ball.contains(&ball.center));
+	assert_eq!(ball.locate(&Point2::new(-3.0, 7.1)), Location::Outside);
+}
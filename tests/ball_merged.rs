@@ -0,0 +1,50 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing, Intersection};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+
+#[test]
+fn merged_disjoint_2_balls() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let a = Ball::new(Point2::new(0.0, 0.0) + offset, 1.0);
+	let b = Ball::new(Point2::new(6.0, 0.0) + offset, 2.0);
+	let ball = a.merged(&b);
+	// Far sides of both balls are on the surface.
+	let far_a = Point2::new(-1.0, 0.0) + offset;
+	let far_b = Point2::new(8.0, 0.0) + offset;
+	assert!(ball.contains(&far_a) && ball.contains(&far_b));
+	assert!((ball.center - (Point2::new(3.5, 0.0) + offset)).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius() - 4.5).abs() <= f64::EPSILON.sqrt());
+	assert_eq!(ball.intersection_kind(&a), Intersection::Contained);
+	assert_eq!(ball.intersection_kind(&b), Intersection::Contained);
+}
+
+#[test]
+fn merged_overlapping_3_balls() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let a = Ball::new(Point3::new(1.0, 2.0, -1.0) + offset, 2.0);
+	let b = Ball::new(Point3::new(2.0, 3.0, 0.0) + offset, 1.5);
+	let ball = b.merged(&a);
+	let direction = (b.center - a.center).normalize();
+	assert!(ball.contains(&(a.center - direction * a.radius())));
+	assert!(ball.contains(&(b.center + direction * b.radius())));
+	let distance = (b.center - a.center).norm();
+	assert!((ball.radius() - (distance + 3.5) / 2.0).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+fn merged_contained_3_balls() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let a = Ball::new(Point3::from(offset), 3.0);
+	let b = Ball::new(Point3::new(1.0, 0.0, 0.0) + offset, 1.0);
+	assert_eq!(a.merged(&b), a);
+	assert_eq!(b.merged(&a), a);
+	// Coincident centers.
+	let b = Ball::new(Point3::from(offset), 1.0);
+	assert_eq!(a.merged(&b), a);
+	assert_eq!(b.merged(&a), a);
+}
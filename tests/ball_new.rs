@@ -0,0 +1,34 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::Point3;
+
+#[test]
+fn new_3_ball_with_radius() {
+	let center = Point3::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(center, 3.0);
+	assert_eq!(ball.center, center);
+	assert_eq!(ball.radius_squared, 9.0);
+	assert_eq!(ball.radius(), 3.0);
+	assert_eq!(ball, Ball::from_radius_squared(center, 9.0));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "negative or infinite radius")]
+fn new_3_ball_with_negative_radius() {
+	let _ball = Ball::new(Point3::<f64>::origin(), -1.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "negative or infinite radius squared")]
+fn new_3_ball_with_infinite_radius_squared() {
+	let _ball = Ball::from_radius_squared(Point3::<f64>::origin(), f64::INFINITY);
+}
@@ -0,0 +1,38 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "parry")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Ball2, Ball3};
+use nalgebra::{Point2, Point3, Vector2, Vector3};
+use parry3d::{bounding_volume::BoundingSphere, shape};
+
+#[test]
+fn convert_3_ball_from_and_to_parry() {
+	let ball = Ball::new(Point3::<f32>::new(-3.0, 7.0, 4.8), 2.0);
+	let sphere = BoundingSphere::from(ball);
+	assert_eq!(sphere.center, ball.center);
+	assert_eq!(sphere.radius, 2.0);
+	assert_eq!(Ball3::from(sphere), ball);
+	assert_eq!(shape::Ball::from(ball).radius, 2.0);
+	let (position, shape) = ball.to_parry_shape();
+	assert_eq!(position.translation.vector, Vector3::new(-3.0, 7.0, 4.8));
+	assert_eq!(shape.radius, 2.0);
+	assert_eq!(Ball3::from_parry_shape(&position, &shape), ball);
+}
+
+#[test]
+fn convert_2_ball_from_and_to_parry() {
+	let ball = Ball::new(Point2::<f32>::new(-3.0, 7.0), 2.0);
+	let sphere = parry2d::bounding_volume::BoundingSphere::from(ball);
+	assert_eq!(sphere.center, ball.center);
+	assert_eq!(Ball2::from(sphere), ball);
+	assert_eq!(parry2d::shape::Ball::from(ball).radius, 2.0);
+	let (position, shape) = ball.to_parry_shape();
+	assert_eq!(position.translation.vector, Vector2::new(-3.0, 7.0));
+	assert_eq!(Ball2::from_parry_shape(&position, &shape), ball);
+}
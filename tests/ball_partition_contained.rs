@@ -0,0 +1,68 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3, U3};
+use std::{cell::Cell, collections::VecDeque};
+
+thread_local! {
+	static WITH_BOUNDS_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Ball counting invocations of [`Enclosing::with_bounds()`].
+#[derive(Debug, Clone)]
+struct CountingBall(Ball<f64, U3>);
+
+impl Enclosing<f64, U3> for CountingBall {
+	fn contains(&self, point: &Point3<f64>) -> bool {
+		self.0.contains(point)
+	}
+	fn with_bounds(bounds: &[Point3<f64>]) -> Option<Self> {
+		WITH_BOUNDS_CALLS.with(|calls| calls.set(calls.get() + 1));
+		Ball::with_bounds(bounds).map(Self)
+	}
+}
+
+fn with_bounds_calls(points: &mut VecDeque<Point3<f64>>) -> usize {
+	WITH_BOUNDS_CALLS.with(|calls| calls.set(0));
+	let _ball = CountingBall::enclosing_points(points);
+	WITH_BOUNDS_CALLS.with(Cell::get)
+}
+
+#[test]
+fn partition_3_cube_by_enclosing_3_ball_of_smaller_3_cube() {
+	let mut primed_calls = 0;
+	let mut unprimed_calls = 0;
+	for _randomize in 0..10 {
+		let offset = Vector3::new(-3.0, 7.0, 4.8);
+		let inner = (0..10_000)
+			.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+			.map(|point| point * 2.0 + offset)
+			.collect::<VecDeque<_>>();
+		let ball = Ball::enclosing_points(&mut inner.clone());
+		let outer = (0..100)
+			.map(|_point| Point3::<f64>::from(Vector3::new_random() - Vector3::from_element(0.5)))
+			.map(|point| point * 4.0 + offset);
+		let mut points = inner;
+		points.extend(outer);
+		let mut unprimed = points.clone();
+		let count = ball.partition_contained(&mut points);
+		assert_eq!(points.len(), unprimed.len());
+		assert!(points.iter().take(count).all(|point| !ball.contains(point)));
+		assert!(points.iter().skip(count).all(|point| ball.contains(point)));
+		assert_eq!(
+			count,
+			unprimed
+				.iter()
+				.filter(|point| !ball.contains(point))
+				.count()
+		);
+		// Ensures primed points require fewer circumscribed balls in total.
+		primed_calls += with_bounds_calls(&mut points);
+		unprimed_calls += with_bounds_calls(&mut unprimed);
+	}
+	assert!(primed_calls < unprimed_calls);
+}
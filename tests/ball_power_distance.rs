@@ -0,0 +1,27 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn power_distance_of_points_to_3_ball() {
+	let ball = Ball {
+		center: Point3::<f64>::new(-3.0, 7.0, 4.8),
+		radius_squared: 9.0,
+	};
+	// At center.
+	assert_eq!(ball.power_distance(&ball.center), -9.0);
+	// On surface.
+	let point = ball.center + Vector3::new(1.0, 2.0, -2.0);
+	assert!(ball.power_distance(&point).abs() <= 1e-12);
+	// Outside.
+	let point = ball.center + Vector3::new(2.0, 4.0, -4.0);
+	assert!((ball.power_distance(&point) - 27.0).abs() <= 1e-12);
+	assert!(ball.power_distance(&point) > 0.0);
+}
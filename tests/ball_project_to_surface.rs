@@ -0,0 +1,30 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{distance, Point3, Vector3};
+
+#[test]
+fn project_points_onto_surface_of_3_ball() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(offset.into(), 2.0);
+	for point in [
+		Point3::new(5.0, -1.0, 3.0),
+		Point3::new(0.1, 0.2, -0.3),
+		Point3::new(0.0, 0.0, 2.0),
+	] {
+		let point = point + offset;
+		let projected = ball.project_to_surface(&point);
+		assert!((distance(&projected, &ball.center) - 2.0).abs() <= f64::EPSILON.sqrt());
+		// Projected point is on the ray from the center through the point.
+		let (direction, projection) = (point - ball.center, projected - ball.center);
+		assert!(direction.cross(&projection).norm() <= f64::EPSILON.sqrt());
+		assert!(direction.dot(&projection) > 0.0);
+	}
+	// Center is projected along first axis.
+	let projected = ball.project_to_surface(&ball.center);
+	assert_eq!(projected, Point3::new(2.0, 0.0, 0.0) + offset);
+}
@@ -0,0 +1,60 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+
+fn finite_differences(bounds: &[Point3<f64>], support: usize) -> Vector3<f64> {
+	// Step size of central differences.
+	let step = 1e-6;
+	Vector3::from_fn(|row, _column| {
+		let delta = Vector3::ith(row, step);
+		let mut forward = bounds.to_vec();
+		forward[support] += delta;
+		let mut backward = bounds.to_vec();
+		backward[support] -= delta;
+		let forward = Ball::with_bounds(&forward).unwrap().radius();
+		let backward = Ball::with_bounds(&backward).unwrap().radius();
+		(forward - backward) / (2.0 * step)
+	})
+}
+
+#[test]
+fn radius_gradient_of_3_ball_wrt_support_by_finite_differences() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let a = Point3::new(1.2, 0.9, 1.0);
+	let b = Point3::new(1.0, -1.3, -1.0);
+	let c = Point3::new(-0.7, 1.0, -1.1);
+	let d = Point3::new(-1.0, -1.0, 1.4);
+	let bounds = [a, b, c, d].map(|bound| bound + offset);
+	for support in 0..bounds.len() {
+		for length in support + 1..=bounds.len() {
+			let bounds = &bounds[..length];
+			let gradient = Ball::radius_gradient_wrt_support(bounds, support).unwrap();
+			let numeric = finite_differences(bounds, support);
+			assert!((gradient - numeric).norm() <= 1e-8);
+		}
+	}
+}
+
+#[test]
+fn radius_gradient_of_3_ball_wrt_antipodal_support() {
+	let a = Point3::new(-3.0, 7.0, 4.8);
+	let b = Point3::new(1.0, 2.0, 3.0);
+	let normal = (b - a).normalize();
+	for (support, normal) in [(0, -normal), (1, normal)] {
+		let gradient = Ball::radius_gradient_wrt_support(&[a, b], support).unwrap();
+		assert!((gradient - normal * 0.5).norm() <= f64::EPSILON.sqrt());
+		assert!((finite_differences(&[a, b], support) - normal * 0.5).norm() <= 1e-8);
+	}
+}
+
+#[test]
+fn radius_gradient_of_3_ball_wrt_single_support() {
+	let a = Point3::new(-3.0, 7.0, 4.8);
+	let gradient = Ball::radius_gradient_wrt_support(&[a], 0).unwrap();
+	assert_eq!(gradient, Vector3::zeros());
+}
@@ -0,0 +1,25 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn radius_squared_covering_points_about_fixed_center() {
+	let center = Point3::<f64>::new(-3.0, 7.0, 4.8);
+	let points = [
+		Vector3::new(1.0, 0.0, 0.0),
+		Vector3::new(0.0, -2.0, 1.0),
+		Vector3::new(2.0, 3.0, -6.0),
+		Vector3::new(-4.0, 0.5, 0.5),
+	]
+	.map(|offset| center + offset);
+	assert_eq!(Ball::radius_squared_covering(&center, &points), 49.0);
+	assert_eq!(Ball::radius_squared_covering(&center, &points[..2]), 5.0);
+	assert_eq!(Ball::radius_squared_covering(&center, []), 0.0);
+}
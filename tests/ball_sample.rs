@@ -0,0 +1,52 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "rand")]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{distance, Vector3};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn sample_interior_of_3_ball_uniformly() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(offset.into(), 2.0);
+	let mut rng = StdRng::seed_from_u64(42);
+	let samples: i32 = 100_000;
+	let mut mean = 0.0;
+	let mut octants = [0; 8];
+	for _sample in 0..samples {
+		let point = ball.sample_interior(&mut rng);
+		assert!(ball.contains(&point));
+		mean += distance(&point, &ball.center) / f64::from(samples);
+		let vector = point - ball.center;
+		octants[usize::from(vector.x > 0.0)
+			+ 2 * usize::from(vector.y > 0.0)
+			+ 4 * usize::from(vector.z > 0.0)] += 1;
+	}
+	// Mean radius is `D / (D + 1) * r = 3 / 4 * 2`.
+	assert!((mean - 1.5).abs() <= 1e-2);
+	// Octants are equally likely.
+	assert!(octants
+		.iter()
+		.all(|&count| (count - samples / 8).abs() <= samples / 80));
+}
+
+#[test]
+fn sample_surface_of_3_ball_uniformly() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(offset.into(), 2.0);
+	let mut rng = StdRng::seed_from_u64(42);
+	let samples: i32 = 100_000;
+	let mut mean = Vector3::zeros();
+	for _sample in 0..samples {
+		let point = ball.sample_surface(&mut rng);
+		assert!((distance(&point, &ball.center) - 2.0).abs() <= f64::EPSILON.sqrt());
+		mean += (point - ball.center) / f64::from(samples);
+	}
+	// Directions cancel out.
+	assert!(mean.norm() <= 2e-2);
+}
@@ -0,0 +1,31 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::Ball;
+use nalgebra::{distance, Point3, Vector3};
+
+#[test]
+fn sdf_of_3_ball_sampled_at_3_grid() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 2.25,
+	};
+	// 3x3x3 grid of unit cells with center cell centered at ball's center.
+	let origin = Point3::from(offset) - Vector3::from_element(1.5);
+	let sdf = ball.sample_sdf_grid(&origin, 1.0, [3, 3, 3]);
+	assert_eq!(sdf.len(), 27);
+	assert!((sdf[13] + 1.5).abs() <= 1e-12);
+	for corner in [0, 2, 6, 8, 18, 20, 24, 26] {
+		let (x, y, z) = (corner / 9, corner / 3 % 3, corner % 3);
+		let center = origin + Vector3::new(x, y, z).cast::<f64>().add_scalar(0.5);
+		let expected = distance(&center, &ball.center) - 1.5;
+		assert!(expected > 0.0);
+		assert!((sdf[corner] - expected).abs() <= 1e-12);
+	}
+}
@@ -0,0 +1,38 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "serde")]
+
+use miniball::Ball;
+use nalgebra::{Point3, U3};
+
+#[test]
+fn serde_round_trip_of_3_ball() {
+	let ball = Ball::new(Point3::new(-3.0, 7.0, 4.8), 1.5);
+	let json = serde_json::to_string(&ball).unwrap();
+	assert_eq!(json, r#"{"center":[-3.0,7.0,4.8],"radius_squared":2.25}"#);
+	let round_trip = serde_json::from_str::<Ball<f64, U3>>(&json).unwrap();
+	assert_eq!(round_trip, ball);
+}
+
+#[test]
+fn serde_rejects_negative_radius_squared() {
+	let json = r#"{"center":[-3.0,7.0,4.8],"radius_squared":-2.25}"#;
+	let error = serde_json::from_str::<Ball<f64, U3>>(json).unwrap_err();
+	assert!(error
+		.to_string()
+		.starts_with("negative or infinite radius squared"));
+}
+
+#[test]
+fn serde_round_trip_of_empty_3_ball() {
+	let ball = Ball::<f64, U3>::empty();
+	let json = serde_json::to_string(&ball).unwrap();
+	assert_eq!(json, r#"{"center":[0.0,0.0,0.0],"radius_squared":-1.0}"#);
+	let round_trip = serde_json::from_str::<Ball<f64, U3>>(&json).unwrap();
+	assert!(round_trip.is_empty());
+	assert_eq!(round_trip, ball);
+}
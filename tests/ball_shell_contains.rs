@@ -0,0 +1,27 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn shell_of_2_ball_contains_points() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 9.0,
+	};
+	// Within shell.
+	assert!(ball.shell_contains(4.0, &(Point2::new(0.0, 2.5) + offset)));
+	// On inner and outer surface.
+	assert!(ball.shell_contains(4.0, &(Point2::new(2.0, 0.0) + offset)));
+	assert!(ball.shell_contains(4.0, &(Point2::new(0.0, -3.0) + offset)));
+	// Within inner hole.
+	assert!(!ball.shell_contains(4.0, &(Point2::new(1.0, 1.0) + offset)));
+	assert!(!ball.shell_contains(4.0, &offset.into()));
+	// Outside shell.
+	assert!(!ball.shell_contains(4.0, &(Point2::new(-3.0, 1.0) + offset)));
+}
@@ -0,0 +1,28 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn signed_distance_of_points_to_3_ball() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(offset.into(), 2.0);
+	// Point at twice the radius.
+	let point = Point3::new(0.0, 4.0, 0.0) + offset;
+	assert_eq!(ball.signed_distance(&point), 2.0);
+	assert_eq!(ball.distance_to_surface(&point), 2.0);
+	// Point on surface.
+	let point = Point3::new(0.0, 0.0, -2.0) + offset;
+	assert_eq!(ball.signed_distance(&point), 0.0);
+	// Point inside.
+	let point = Point3::new(0.5, 0.0, 0.0) + offset;
+	assert_eq!(ball.signed_distance(&point), -1.5);
+	assert_eq!(ball.distance_to_surface(&point), 1.5);
+	assert_eq!(ball.signed_distance(&ball.center), -2.0);
+}
@@ -0,0 +1,30 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Matrix3, Point3, Vector3};
+
+#[test]
+fn tangent_frame_of_3_ball() {
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(offset.into(), 2.0);
+	for direction in [
+		Vector3::new(1.0, 2.0, -2.0),
+		Vector3::new(0.0, 0.0, 1.0),
+		Vector3::new(0.0, 0.0, -1.0),
+		Vector3::new(-0.3, 0.1, -0.9),
+	] {
+		let surface_point = ball.center + direction.normalize() * 2.0;
+		let (normal, basis) = ball.tangent_frame(&surface_point);
+		assert!((normal - direction.normalize()).norm() < epsilon);
+		assert_eq!(basis.column(2), normal);
+		assert!((basis.transpose() * basis - Matrix3::identity()).norm() < epsilon);
+	}
+	let (normal, basis) = ball.tangent_frame(&Point3::from(offset));
+	assert_eq!(normal, Vector3::x());
+	assert!((basis.transpose() * basis - Matrix3::identity()).norm() < epsilon);
+}
@@ -0,0 +1,27 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn transform_of_3_ball_to_unit_3_ball() {
+	let ball = Ball {
+		center: Point3::<f64>::new(-3.0, 7.0, 4.8),
+		radius_squared: 9.0,
+	};
+	let point = ball.center + Vector3::new(1.0, 2.0, 2.0);
+	let (scale, translation) = ball.to_unit_transform();
+	// Center maps to origin.
+	assert!((ball.center.coords * scale + translation).norm() <= 1e-12);
+	assert!(ball.to_unit(&ball.center).coords.norm() <= 1e-12);
+	// Surface point maps to unit sphere.
+	let unit = point.coords * scale + translation;
+	assert!((unit.norm() - 1.0).abs() <= 1e-12);
+	assert!((ball.to_unit(&point).coords - unit).norm() <= 1e-12);
+	// Inverse maps back.
+	assert!((ball.from_unit(&ball.to_unit(&point)) - point).norm() <= 1e-12);
+}
@@ -0,0 +1,25 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{distance, Isometry3, Point3, Vector3};
+
+#[test]
+fn surface_point_stays_on_3_ball_transformed_by_isometry() {
+	let ball = Ball {
+		center: Point3::<f64>::new(-3.0, 7.0, 4.8),
+		radius_squared: 9.0,
+	};
+	let point = ball.center + Vector3::new(1.0, 2.0, 2.0);
+	let iso = Isometry3::new(Vector3::new(1.2, -0.5, 3.0), Vector3::new(0.3, -1.1, 0.7));
+	let transformed = ball.transformed_by(&iso);
+	assert_eq!(transformed.radius_squared, ball.radius_squared);
+	assert!((transformed.center - iso * ball.center).norm() <= 1e-12);
+	let radius = transformed.radius_squared.sqrt();
+	assert!((distance(&(iso * point), &transformed.center) - radius).abs() <= 1e-12);
+}
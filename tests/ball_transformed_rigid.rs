@@ -0,0 +1,54 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Matrix3, Point3, Rotation3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn rigidly_transformed_3_ball_round_trips() {
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points(&mut points);
+	let rotation = Rotation3::from_euler_angles(0.3, -1.2, 2.1);
+	let translation = Vector3::new(1.0, -2.0, 0.5);
+	let transformed = ball.transformed_rigid(&rotation, &translation);
+	assert_eq!(transformed.radius_squared, ball.radius_squared);
+	let mut transformed_points = points
+		.iter()
+		.map(|point| rotation * point + translation)
+		.collect::<VecDeque<_>>();
+	let minimum = Ball::enclosing_points(&mut transformed_points);
+	assert!(transformed.approx_eq(&minimum, epsilon));
+	let round_trip = transformed.transformed_rigid(&rotation.inverse(), &Vector3::zeros());
+	let round_trip = round_trip.translated(&-(rotation.inverse() * translation));
+	assert!(round_trip.approx_eq(&ball, epsilon));
+}
+
+#[test]
+fn affinely_transformed_3_ball_contains_transformed_support_points() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points(&mut points);
+	let matrix = Matrix3::new(2.0, 0.5, 0.0, -0.3, 0.7, 0.1, 0.0, 1.2, 3.0);
+	let translation = Vector3::new(1.0, -2.0, 0.5);
+	let transformed = ball.transformed_affine(&matrix, &translation);
+	assert!(points
+		.iter()
+		.all(|point| transformed.contains(&(matrix * point + translation))));
+	let scaled = Ball::new(Point3::origin(), 1.0)
+		.transformed_affine(&(Matrix3::identity() * 2.0), &translation);
+	assert!((scaled.radius_squared - 4.0).abs() < f64::EPSILON.sqrt());
+	assert_eq!(scaled.center, Point3::from(translation));
+}
@@ -0,0 +1,46 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point2, Vector2};
+use std::collections::VecDeque;
+
+#[test]
+fn translated_2_ball_contains_translated_support_points() {
+	let mut points = VecDeque::from([
+		Point2::<f64>::new(-1.0, 0.0),
+		Point2::new(1.0, 0.0),
+		Point2::new(0.0, 1.0),
+	]);
+	let ball = Ball::enclosing_points(&mut points);
+	let offset = Vector2::new(-3.0, 7.0);
+	let translated = ball.translated(&offset);
+	assert_eq!(translated.center, ball.center + offset);
+	assert_eq!(translated.radius_squared, ball.radius_squared);
+	for point in &points {
+		assert!(translated.contains(&(point + offset)));
+	}
+}
+
+#[test]
+fn scaled_2_ball_by_negative_factor() {
+	let ball = Ball::new(Point2::<f64>::new(-3.0, 7.0), 2.0);
+	let scaled = ball.scaled(-1.5);
+	assert_eq!(scaled.center, ball.center);
+	assert_eq!(scaled.radius(), 3.0);
+	let scaled = ball.scaled_about(&Point2::new(-1.0, 7.0), -1.5);
+	assert_eq!(scaled.center, Point2::new(2.0, 7.0));
+	assert_eq!(scaled.radius(), 3.0);
+}
+
+#[test]
+#[should_panic(expected = "infinite factor")]
+#[cfg(debug_assertions)]
+fn scaled_2_ball_by_infinite_factor() {
+	let _ball = Ball::new(Point2::<f64>::new(-3.0, 7.0), 2.0).scaled(f64::INFINITY);
+}
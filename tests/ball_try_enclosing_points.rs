@@ -0,0 +1,67 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{Ball, Enclosing, EnclosingError};
+use nalgebra::{Point2, Vector2};
+use std::collections::VecDeque;
+
+#[test]
+fn try_minimum_2_ball_enclosing_2_square() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let mut points = [
+		(-1.0, -1.0),
+		(-1.0, 1.0),
+		(1.0, -1.0),
+		(1.0, 1.0),
+		(0.5, 0.0),
+	]
+	.map(|(x, y)| Point2::new(x, y) + offset)
+	.into_iter()
+	.collect::<VecDeque<_>>();
+	let ball = Ball::try_enclosing_points(&mut points).unwrap();
+	assert!((ball.center - Point2::from(offset)).norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius_squared - 2.0).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+fn try_minimum_2_ball_enclosing_empty_point_set() {
+	let mut points = VecDeque::<Point2<f64>>::new();
+	let error = Ball::try_enclosing_points(&mut points).unwrap_err();
+	assert_eq!(error, EnclosingError::EmptyPointSet);
+	assert_eq!(error.to_string(), "empty point set");
+	assert_eq!(
+		EnclosingError::NumericalInstability.to_string(),
+		"numerical instability"
+	);
+}
+
+#[test]
+fn try_minimum_2_ball_enclosing_non_finite_points() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let mut points = [
+		(-1.0, -1.0),
+		(f64::NAN, 1.0),
+		(1.0, -1.0),
+		(1.0, f64::INFINITY),
+	]
+	.map(|(x, y)| Point2::new(x, y) + offset)
+	.into_iter()
+	.collect::<VecDeque<_>>();
+	let error = Ball::try_enclosing_points(&mut points).unwrap_err();
+	assert_eq!(error, EnclosingError::NonFinitePoint);
+	assert_eq!(error.to_string(), "non-finite point");
+	assert_eq!(points.len(), 4);
+	let ball = Ball::new(Point2::from(offset), 2.0);
+	assert_eq!(ball.try_contains(&points[0]), Some(true));
+	assert_eq!(ball.try_contains(&points[1]), None);
+	assert_eq!(ball.try_contains(&points[3]), None);
+	assert_eq!(
+		ball.try_contains(&(Point2::new(3.0, 0.0) + offset)),
+		Some(false)
+	);
+}
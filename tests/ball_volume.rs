@@ -0,0 +1,36 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::f64::consts::PI;
+use miniball::Ball;
+use nalgebra::{Point1, Point2, Point3, Point4, Point6};
+
+#[test]
+fn volume_and_surface_area_of_n_balls() {
+	let ball = Ball::new(Point1::new(-3.0), 2.0);
+	let one = (ball.volume(), ball.surface_area());
+	let ball = Ball::new(Point2::new(-3.0, 7.0), 1.0);
+	let two = (ball.volume(), ball.surface_area());
+	let ball = Ball::new(Point3::new(-3.0, 7.0, 4.8), 1.0);
+	let three = (ball.volume(), ball.surface_area());
+	let ball = Ball::new(Point3::new(-3.0, 7.0, 4.8), 2.0);
+	let three_scaled = (ball.volume(), ball.surface_area());
+	let ball = Ball::new(Point4::origin(), 1.0);
+	let four = (ball.volume(), ball.surface_area());
+	let ball = Ball::new(Point6::origin(), 1.0);
+	let six = (ball.volume(), ball.surface_area());
+	for (actual, expected) in [
+		(one, (4.0, 2.0)),
+		(two, (PI, 2.0 * PI)),
+		(three, (4.0 / 3.0 * PI, 4.0 * PI)),
+		(three_scaled, (32.0 / 3.0 * PI, 16.0 * PI)),
+		(four, (PI * PI / 2.0, 2.0 * PI * PI)),
+		(six, (PI.powi(3) / 6.0, PI.powi(3))),
+	] {
+		assert!((actual.0 - expected.0).abs() <= 1e-10);
+		assert!((actual.1 - expected.1).abs() <= 1e-10);
+	}
+}
@@ -0,0 +1,29 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::{Point3, Vector3};
+use std::f64::consts::PI;
+
+#[test]
+fn volume_preserving_merge_of_3_balls() {
+	let volume = |ball: &Ball<f64, _>| 4.0 / 3.0 * PI * ball.radius_squared.sqrt().powi(3);
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let balls = [
+		Ball {
+			center: Point3::new(-2.0, 0.0, 0.0) + offset,
+			radius_squared: 4.0,
+		},
+		Ball {
+			center: Point3::new(2.0, 0.0, 0.0) + offset,
+			radius_squared: 4.0,
+		},
+	];
+	let merged = Ball::volume_preserving_merge(&balls).unwrap();
+	assert!((merged.center - Point3::from(offset)).norm() <= 1e-12);
+	assert!((volume(&merged) - balls.iter().map(volume).sum::<f64>()).abs() <= 1e-12);
+	assert!(Ball::<f64, _>::volume_preserving_merge(&balls[..0]).is_none());
+}
@@ -0,0 +1,24 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::Ball;
+use nalgebra::Point2;
+
+#[test]
+fn voxel_bounds_of_2_ball() {
+	// Unit ball at origin touching cell boundaries.
+	let ball = Ball {
+		center: Point2::<f64>::origin(),
+		radius_squared: 1.0,
+	};
+	assert_eq!(ball.voxel_bounds(0.5), ([-2, -2], [2, 2]));
+	// Unit ball in between cell boundaries.
+	let ball = Ball {
+		center: Point2::new(0.25, -0.6),
+		radius_squared: 1.0,
+	};
+	assert_eq!(ball.voxel_bounds(0.5), ([-2, -4], [3, 1]));
+}
@@ -0,0 +1,45 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::Ball;
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn weighted_coverage_of_2_ball_ignoring_light_outliers() {
+	let offset = Vector2::new(-3.0, 7.0);
+	let ball = Ball {
+		center: offset.into(),
+		radius_squared: 9.0,
+	};
+	let inliers = [
+		Point2::new(1.0, 0.0),
+		Point2::new(0.0, -2.0),
+		Point2::new(-3.0, 0.0),
+	]
+	.map(|point| point + offset);
+	let outliers = [Point2::new(4.0, 0.0), Point2::new(0.0, -9.0)].map(|point| point + offset);
+	let weighted_points = inliers
+		.iter()
+		.map(|point| (point, 100.0))
+		.chain(outliers.iter().map(|point| (point, 1.0)));
+	let coverage = ball.weighted_coverage(weighted_points);
+	assert_eq!(coverage, 300.0 / 302.0);
+	assert!(coverage > 0.99);
+	let weighted_points = outliers.iter().map(|point| (point, 1.0));
+	assert_eq!(ball.weighted_coverage(weighted_points), 0.0);
+}
+
+#[test]
+#[should_panic(expected = "non-positive total weight")]
+fn weighted_coverage_of_2_ball_without_points() {
+	let ball = Ball {
+		center: Point2::new(-3.0, 7.0),
+		radius_squared: 9.0,
+	};
+	let _coverage = ball.weighted_coverage([]);
+}
@@ -0,0 +1,37 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Ball, Enclosing};
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn circumscribed_3_ball_with_barycentric_weights() {
+	let epsilon = f64::EPSILON.sqrt();
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let bounds = [
+		Point3::new(1.0, 0.2, -0.3),
+		Point3::new(-0.8, 1.1, 0.0),
+		Point3::new(0.1, -1.0, 0.7),
+		Point3::new(0.3, 0.4, 1.2),
+	]
+	.map(|bound| bound + offset);
+	for length in 1..=bounds.len() {
+		let bounds = &bounds[..length];
+		let (ball, weights) = Ball::with_bounds_barycentric(bounds).unwrap();
+		let expected = Ball::with_bounds(bounds).unwrap();
+		assert!(ball.approx_eq(&expected, epsilon));
+		assert!((weights.sum() - 1.0).abs() < epsilon);
+		assert!(weights.iter().skip(length).all(|&weight| weight == 0.0));
+		let center = bounds
+			.iter()
+			.zip(weights.iter())
+			.fold(Vector3::zeros(), |center, (bound, weight)| {
+				center + bound.coords * *weight
+			});
+		assert!((center - ball.center.coords).norm() < epsilon);
+	}
+	assert!(Ball::<f64, _>::with_bounds_barycentric(&[] as &[Point3<f64>]).is_none());
+}
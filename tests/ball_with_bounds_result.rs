@@ -0,0 +1,40 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Ball2, WithBoundsError};
+use nalgebra::{Point2, Vector2};
+
+#[test]
+fn circumscribed_2_ball_with_bounds_result() {
+	let offset = Vector2::<f64>::new(-3.0, 7.0);
+	let [a, b, c, d] =
+		[(1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (3.0, 0.0)].map(|(x, y)| Point2::new(x, y) + offset);
+	let ball = Ball::with_bounds_result(&[a, b, c]).unwrap();
+	assert_eq!(ball.center, offset.into());
+	assert_eq!(ball.radius_squared, 1.0);
+	assert_eq!(
+		Ball2::<f64>::with_bounds_result(&[]),
+		Err(WithBoundsError::EmptyBounds)
+	);
+	assert_eq!(
+		Ball::with_bounds_result(&[a, b, c, d]),
+		Err(WithBoundsError::TooManyBounds)
+	);
+	assert_eq!(
+		Ball::with_bounds_result(&[a, b, d]),
+		Err(WithBoundsError::AffinelyDependent)
+	);
+	assert_eq!(
+		Ball::with_bounds_result(&[a, Point2::new(f64::MAX, 0.0)]),
+		Err(WithBoundsError::NonFinite)
+	);
+	assert_eq!(
+		Ball::with_bounds_result(&[a, Point2::new(f64::NAN, 0.0)]),
+		Err(WithBoundsError::NonFinite)
+	);
+}
@@ -0,0 +1,62 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, BallWithRadius};
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn cached_radius_of_3_ball() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(Point3::from(offset), 2.0);
+	let mut cached = BallWithRadius::from(ball);
+	assert_eq!(cached.radius(), 2.0);
+	for point in [
+		Point3::new(1.0, 2.0, -2.0),
+		Point3::new(0.5, 0.0, 0.0),
+		Point3::origin(),
+	] {
+		let point = point + offset;
+		assert_eq!(cached.signed_distance(&point), ball.signed_distance(&point));
+		assert_eq!(
+			cached.distance_to_surface(&point),
+			ball.distance_to_surface(&point)
+		);
+		assert_eq!(
+			cached.project_to_surface(&point),
+			ball.project_to_surface(&point)
+		);
+	}
+	assert_eq!(cached.aabb(), ball.aabb());
+	cached.set_radius(3.0);
+	cached.set_center(Point3::origin());
+	assert_eq!(cached.radius(), 3.0);
+	assert_eq!(cached.ball().radius_squared, 9.0);
+	assert_eq!(Ball::from(cached), Ball::new(Point3::origin(), 3.0));
+}
+
+#[test]
+fn cached_radius_of_grown_and_interpolated_3_balls() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let ball = Ball::new(Point3::from(offset), 2.0);
+	let other = Ball::new(Point3::new(1.0, 2.0, -2.0) + offset, 4.0);
+	let cached = BallWithRadius::from(ball);
+	let other_cached = BallWithRadius::from(other);
+	for margin in [0.5, -0.5, -3.0] {
+		let grown = cached.grow_by(margin);
+		assert_eq!(grown.radius(), grown.ball().radius());
+		assert_eq!(grown.into_ball(), ball.grow_by(margin));
+		let shrunk = cached.shrink_by(margin);
+		assert_eq!(shrunk.radius(), shrunk.ball().radius());
+		assert_eq!(shrunk.into_ball(), ball.shrink_by(margin));
+	}
+	for t in [0.0, 0.25, 1.0, -2.0] {
+		let lerped = cached.lerp(&other_cached, t);
+		assert_eq!(lerped.radius(), lerped.ball().radius());
+		assert_eq!(lerped.into_ball(), ball.lerp(&other, t));
+	}
+}
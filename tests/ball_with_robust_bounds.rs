@@ -0,0 +1,86 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(all(feature = "std", feature = "robust"))]
+
+use miniball::{Ball, Enclosing, WithBoundsError};
+use nalgebra::{distance, Point2, Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn circumscribed_3_ball_of_nearly_coplanar_f32_points() {
+	// Flat tetrahedron whose Gram matrix is singular in `f32`.
+	let height = 1e-4;
+	let points = [
+		Point3::<f32>::new(1.0, 1.0, height),
+		Point3::new(-1.0, -1.0, height),
+		Point3::new(1.0, -1.0, -height),
+		Point3::new(-1.0, 1.0, -height),
+		Point3::new(0.5, 0.0, 0.0),
+		Point3::new(0.0, -0.5, height / 2.0),
+	];
+	let ball = Ball::with_bounds(&points[..4]).unwrap();
+	assert!(ball.center.coords.norm() <= f32::EPSILON.sqrt());
+	assert!((ball.radius_squared - 2.0).abs() <= f32::EPSILON.sqrt());
+	let ball = Ball::enclosing_points(&mut points.iter().copied().collect::<VecDeque<_>>());
+	assert!(points.iter().all(|point| ball.contains(point)));
+	assert!((ball.radius_squared - 2.0).abs() <= f32::EPSILON.sqrt());
+}
+
+#[test]
+fn circumscribed_2_ball_of_nearly_collinear_f32_points() {
+	// Flat triangle whose Gram matrix is singular in `f32`.
+	let height = 1e-5;
+	let points = [
+		Point2::<f32>::new(0.0, 0.0),
+		Point2::new(1.0, 0.0),
+		Point2::new(0.5, height),
+	];
+	let ball = Ball::with_bounds(&points).unwrap();
+	let radius = ball.radius();
+	for point in &points {
+		assert!((distance(point, &ball.center) / radius - 1.0).abs() <= f32::EPSILON.sqrt());
+	}
+	assert!(Ball::with_bounds(&[points[0], points[1], points[0]]).is_none());
+}
+
+#[test]
+fn circumscribed_2_ball_of_f32_points_collinear_within_epsilon() {
+	// Flat triangle whose determinant is exact but not resolvable in `f32`.
+	let points = [
+		Point2::<f32>::new(0.0, 0.0),
+		Point2::new(1.0, 0.0),
+		Point2::new(0.5, 1e-9),
+	];
+	assert_eq!(
+		Ball::with_bounds_result(&points),
+		Err(WithBoundsError::AffinelyDependent)
+	);
+}
+
+#[test]
+fn circumscribed_3_balls_of_random_tetrahedra() {
+	for _tetrahedron in 0..1_000 {
+		let points = [(); 4].map(|()| Point3::from(Vector3::<f64>::new_random()));
+		let ball = Ball::with_bounds(&points).unwrap();
+		let radius = ball.radius();
+		for point in &points {
+			assert!((distance(point, &ball.center) / radius - 1.0).abs() <= f64::EPSILON.sqrt());
+		}
+	}
+}
+
+#[test]
+fn circumscribed_3_ball_with_4_coplanar_points() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let a = Point3::new(1.0, 1.0, 1.0);
+	let b = Point3::new(1.0, -1.0, -1.0);
+	let c = Point3::new(-1.0, 1.0, -1.0);
+	// Exactly coplanar unlike the rounded centroid of `a`, `b`, and `c`.
+	let d = a + (b - c);
+	let ball = Ball::with_bounds(&[a, b, c, d].map(|bound| bound + offset));
+	assert_eq!(ball, None);
+}
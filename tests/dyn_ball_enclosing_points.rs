@@ -0,0 +1,66 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, DynBall, Enclosing};
+use nalgebra::{DVector, Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn circumscribed_dyn_3_ball_with_4_points() {
+	let offset = DVector::<f64>::from_vec(vec![-3.0, 7.0, 4.8]);
+	let bounds = [
+		[1.0, 1.0, 1.0],
+		[1.0, -1.0, -1.0],
+		[-1.0, 1.0, -1.0],
+		[-1.0, -1.0, 1.0],
+	]
+	.map(|bound| DVector::from_row_slice(&bound) + &offset);
+	let DynBall {
+		center,
+		radius_squared,
+	} = DynBall::with_bounds(&bounds).unwrap();
+	assert_eq!(center, offset);
+	assert_eq!(radius_squared, 3.0);
+	assert!(DynBall::<f64>::with_bounds(&[]).is_none());
+	assert!(DynBall::with_bounds(&vec![bounds[0].clone(); 5]).is_none());
+}
+
+#[test]
+fn minimum_dyn_3_ball_matches_minimum_3_ball() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<VecDeque<_>>();
+	let ball = Ball::enclosing_points(&mut points.clone());
+	let mut dyn_points = points
+		.iter()
+		.map(|point| DVector::from_column_slice(point.coords.as_slice()))
+		.collect::<VecDeque<_>>();
+	let dyn_ball = DynBall::enclosing_points(&mut dyn_points);
+	assert_eq!(dyn_ball.dim(), 3);
+	assert_eq!(dyn_points.len(), points.len());
+	assert!(dyn_points.iter().all(|point| dyn_ball.contains(point)));
+	let center = DVector::from_column_slice(ball.center.coords.as_slice());
+	assert!((&dyn_ball.center - center).norm() <= f64::EPSILON.sqrt());
+	assert!((dyn_ball.radius() - ball.radius()).abs() <= f64::EPSILON.sqrt());
+}
+
+#[test]
+fn minimum_dyn_6_ball_enclosing_6_cube() {
+	let mut points = (0..64_usize)
+		.map(|corner| {
+			DVector::<f64>::from_fn(
+				6,
+				|row, _column| if corner >> row & 1 == 1 { 1.0 } else { -1.0 },
+			)
+		})
+		.collect::<VecDeque<_>>();
+	let ball = DynBall::enclosing_points(&mut points);
+	assert!(ball.center.norm() <= f64::EPSILON.sqrt());
+	assert!((ball.radius_squared - 6.0).abs() <= f64::EPSILON.sqrt());
+}
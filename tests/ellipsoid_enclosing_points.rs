@@ -0,0 +1,58 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::Ellipsoid;
+use nalgebra::{Matrix2, Matrix3, Point2, Point3, Rotation2, Vector3};
+
+#[test]
+fn minimum_3_ellipsoid_enclosing_box() {
+	let epsilon = 1e-9;
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let half_extents = Vector3::new(1.0, 2.0, 3.0);
+	let mut points = Vec::new();
+	for x in [-1.0, 1.0] {
+		for y in [-1.0, 1.0] {
+			for z in [-1.0, 1.0] {
+				points.push(Point3::from(
+					half_extents.component_mul(&Vector3::new(x, y, z)),
+				));
+			}
+		}
+	}
+	// Interior points not affecting the ellipsoid but its initial weights.
+	for _ in 0..20 {
+		let point =
+			(Vector3::new_random() - Vector3::from_element(0.5)).component_mul(&half_extents);
+		points.push(point.into());
+	}
+	let points = points
+		.into_iter()
+		.map(|point| point + offset)
+		.collect::<Vec<_>>();
+	let ellipsoid = Ellipsoid::enclosing_points(&points, epsilon);
+	assert!(points.iter().all(|point| ellipsoid.contains(point)));
+	assert!(!ellipsoid.contains(&(Point3::from(half_extents * 2.0) + offset)));
+	assert!((ellipsoid.center - Point3::from(offset)).norm() < 1e-6);
+	// Axis-aligned semi-axes of box's half-extents scaled by square root of dimension.
+	let shape = Matrix3::from_diagonal(
+		&half_extents.map(|half_extent| (3.0 * half_extent * half_extent).recip()),
+	);
+	assert!((ellipsoid.shape - shape).norm() < 1e-6);
+}
+
+#[test]
+fn minimum_2_ellipsoid_enclosing_rotated_points() {
+	let rotation = Rotation2::new(0.3);
+	let points = [(-2.0, 0.0), (2.0, 0.0), (0.0, -1.0), (0.0, 1.0), (0.5, 0.5)]
+		.map(|(x, y)| rotation * Point2::<f64>::new(x, y));
+	let ellipsoid = Ellipsoid::enclosing_points(&points, 1e-9);
+	assert!(points.iter().all(|point| ellipsoid.contains(point)));
+	assert!(ellipsoid.center.coords.norm() < 1e-6);
+	let shape = rotation * Matrix2::new(0.25, 0.0, 0.0, 1.0) * rotation.inverse();
+	assert!((ellipsoid.shape - shape).norm() < 1e-6);
+}
@@ -0,0 +1,102 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+
+use miniball::{
+	nalgebra::{distance, DimNameSum, Point3, Vector3, U1, U3, U4},
+	Ball, Deque, Enclosing, Location, OVec,
+};
+use std::{cell::Cell, collections::VecDeque};
+
+thread_local! {
+	static WITH_BOUNDS_STEPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Custom ball storing its radius instead of its radius squared.
+#[derive(Debug, Clone)]
+struct Sphere {
+	center: Point3<f64>,
+	radius: f64,
+}
+
+impl Enclosing<f64, U3> for Sphere {
+	fn contains(&self, point: &Point3<f64>) -> bool {
+		distance(&self.center, point) <= self.radius * (1.0 + f64::EPSILON.sqrt())
+	}
+	fn with_bounds(bounds: &[Point3<f64>]) -> Option<Self> {
+		Ball::with_bounds(bounds).map(|ball| Self {
+			radius: ball.radius(),
+			center: ball.center,
+		})
+	}
+}
+
+#[test]
+fn minimum_custom_3_ball_enclosing_points() {
+	let offset = Vector3::new(-3.0, 7.0, 4.8);
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::<f64>::new_random() - Vector3::from_element(0.5)))
+		.map(|point| point + offset)
+		.collect::<VecDeque<_>>();
+	let sphere = Sphere::enclosing_points(&mut points);
+	let ball = Ball::enclosing_points(&mut points);
+	let epsilon = f64::EPSILON.sqrt();
+	assert!(distance(&sphere.center, &ball.center) <= epsilon);
+	assert!((sphere.radius - ball.radius()).abs() <= epsilon);
+	assert!(points.iter().all(|point| sphere.contains(point)));
+}
+
+#[test]
+fn minimum_3_ball_enclosing_points_with_prescribed_bound() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let mut points = [(-1.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 0.5, 0.0)]
+		.map(|(x, y, z)| Point3::new(x, y, z) + offset)
+		.into_iter()
+		.collect::<VecDeque<_>>();
+	let bound = Point3::new(0.0, 3.0, 0.0) + offset;
+	let mut bounds = OVec::<_, U4>::new();
+	bounds.push(bound);
+	let ball = Ball::enclosing_points_with_bounds(&mut points, &mut bounds).unwrap();
+	assert_eq!(points.len(), 3);
+	assert_eq!(bounds.as_slice(), &[bound]);
+	assert_eq!(ball.locate(&bound), Location::Surface);
+	assert!(points.iter().all(|point| ball.contains(point)));
+}
+
+/// Ball counting invocations of its overridden recursion step.
+#[derive(Debug, Clone)]
+struct CountingBall(Ball<f64, U3>);
+
+impl Enclosing<f64, U3> for CountingBall {
+	fn contains(&self, point: &Point3<f64>) -> bool {
+		self.0.contains(point)
+	}
+	fn with_bounds(bounds: &[Point3<f64>]) -> Option<Self> {
+		Ball::with_bounds(bounds).map(Self)
+	}
+	fn enclosing_points_with_bounds(
+		points: &mut impl Deque<Point3<f64>>,
+		bounds: &mut OVec<Point3<f64>, DimNameSum<U3, U1>>,
+	) -> Option<Self> {
+		WITH_BOUNDS_STEPS.with(|steps| steps.set(steps.get() + 1));
+		Ball::enclosing_points_with_bounds(points, bounds).map(Self)
+	}
+}
+
+#[test]
+fn minimum_custom_3_ball_enclosing_points_with_overridden_step() {
+	let mut points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::<f64>::new_random()))
+		.collect::<VecDeque<_>>();
+	WITH_BOUNDS_STEPS.with(|steps| steps.set(0));
+	let counting = CountingBall::enclosing_points(&mut points);
+	assert!(WITH_BOUNDS_STEPS.with(Cell::get) > 0);
+	let ball = Ball::enclosing_points(&mut points);
+	let epsilon = f64::EPSILON.sqrt();
+	assert!(distance(&counting.0.center, &ball.center) <= epsilon);
+	assert!((counting.0.radius() - ball.radius()).abs() <= epsilon);
+}
@@ -0,0 +1,48 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+#![cfg(feature = "std")]
+#![allow(clippy::float_cmp)]
+
+use miniball::{Ball, Enclosing, MinBall};
+use nalgebra::{Point3, Vector3};
+use std::collections::VecDeque;
+
+#[test]
+fn minimum_3_ball_of_streamed_points() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	let points = (0..1_000)
+		.map(|_point| Point3::from(Vector3::new_random() - Vector3::from_element(0.5) + offset))
+		.collect::<Vec<_>>();
+	let mut min_ball = MinBall::new();
+	assert!(min_ball.ball().is_none());
+	min_ball.extend(points.iter().copied());
+	assert_eq!(min_ball.len(), 1_000);
+	let running = *min_ball.ball().unwrap();
+	assert!(points.iter().all(|point| running.contains(point)));
+	let ball = min_ball.finish();
+	assert!(ball.radius_squared <= running.radius_squared);
+	let minimum = Ball::enclosing_points(&mut points.iter().copied().collect::<VecDeque<_>>());
+	assert!(ball.approx_eq(&minimum, f64::EPSILON.sqrt()));
+	let ball = points.into_iter().collect::<MinBall<_, _>>().finish();
+	assert!(ball.approx_eq(&minimum, f64::EPSILON.sqrt()));
+}
+
+#[test]
+fn exact_running_ball_of_contained_points() {
+	let a = Point3::<f64>::new(-1.0, 0.0, 0.0);
+	let b = Point3::new(1.0, 0.0, 0.0);
+	let mut min_ball = MinBall::default();
+	min_ball.push(a);
+	min_ball.push(a);
+	assert!(min_ball.is_exact());
+	assert_eq!(min_ball.ball(), Some(&Ball::new(a, 0.0)));
+	min_ball.push(b);
+	assert!(!min_ball.is_exact());
+	assert!(min_ball.ball().unwrap().contains(&a));
+	assert!(min_ball.ball().unwrap().contains(&b));
+	assert_eq!(min_ball.finish(), Ball::from_diameter(&a, &b));
+}
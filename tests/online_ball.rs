@@ -0,0 +1,35 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{Enclosing, OnlineBall};
+use nalgebra::{Point3, Vector3};
+
+#[test]
+fn online_3_ball_enclosing_3_cube() {
+	let offset = Vector3::<f64>::new(-3.0, 7.0, 4.8);
+	// Exact radius of minimum 3-ball enclosing 3-cube of half-extent 1.
+	let radius = 3f64.sqrt();
+	for _randomize in 0..100 {
+		let mut points = (0..1_000)
+			.map(|_point| (Vector3::new_random() - Vector3::from_element(0.5)) * 2.0)
+			.map(|point| Point3::from(point + offset))
+			.collect::<Vec<_>>();
+		// Inserts corners of 3-cube at random positions of stream.
+		for corner in 0..8 {
+			let corner =
+				Vector3::new(1, 2, 4).map(|axis| if corner & axis == 0 { -1.0 } else { 1.0 });
+			let index = usize::from(rand::random::<u16>()) % points.len();
+			points.insert(index, Point3::from(corner + offset));
+		}
+		let mut online = OnlineBall::new();
+		assert!(online.ball().is_none());
+		online.extend(&points);
+		let ball = online.ball().unwrap();
+		assert!(points.iter().all(|point| ball.contains(point)));
+		assert!(ball.radius() >= radius * (1.0 - f64::EPSILON.sqrt()));
+		assert!(ball.radius() <= radius * 1.5);
+	}
+}
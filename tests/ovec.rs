@@ -0,0 +1,43 @@
+// Copyright © 2022-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use miniball::{CapacityError, OVec};
+use nalgebra::U3;
+
+#[test]
+fn ovec_within_capacity() {
+	let mut vec = OVec::<i32, U3>::new();
+	assert!(vec.is_empty());
+	assert_eq!(vec.capacity(), 3);
+	vec.push(1);
+	vec.push(2);
+	assert_eq!(vec.get(1), Some(&2));
+	assert_eq!(vec.get(2), None);
+	vec.as_mut_slice()[0] = 3;
+	assert!(vec.iter().eq(&[3, 2]));
+	assert!((&vec).into_iter().eq(&[3, 2]));
+	vec.clear();
+	assert!(vec.is_empty());
+	assert_eq!(vec.pop(), None);
+	let vec = [4, 5, 6].into_iter().collect::<OVec<_, U3>>();
+	assert!(vec.is_full());
+	assert_eq!(vec.as_slice(), &[4, 5, 6]);
+	assert_eq!(
+		OVec::<i32, U3>::try_from(&[7, 8][..]).unwrap().as_slice(),
+		&[7, 8]
+	);
+	assert_eq!(
+		OVec::<i32, U3>::try_from(&[1, 2, 3, 4][..]),
+		Err(CapacityError)
+	);
+	assert_eq!(CapacityError.to_string(), "insufficient capacity");
+}
+
+#[test]
+#[should_panic(expected = "insufficient capacity")]
+fn ovec_beyond_capacity() {
+	let _vec = (0..4).collect::<OVec<i32, U3>>();
+}